@@ -9,15 +9,16 @@ use ruff_python_ast::{ModModule, StringLiteral};
 use ruff_python_parser::Parsed;
 
 use crate::cache::KeyValueCache;
-use crate::db::{LintDb, LintJar, QueryResult};
+use crate::db::{LintDb, LintJar, QueryResult, SemanticDb, SemanticJar};
 use crate::files::FileId;
 use crate::module::resolve_module;
 use crate::parse::parse;
-use crate::semantic::{infer_definition_type, infer_symbol_public_type, Type};
+use crate::semantic::{infer_definition_type, infer_expr_type, infer_symbol_public_type, Type};
 use crate::semantic::{
     resolve_global_symbol, semantic_index, Definition, GlobalSymbolId, SemanticIndex, SymbolId,
 };
 use crate::source::{source_text, Source};
+use ruff_python_ast as ast;
 
 #[tracing::instrument(level = "debug", skip(db))]
 pub(crate) fn lint_syntax(db: &dyn LintDb, file_id: FileId) -> QueryResult<Diagnostics> {
@@ -96,6 +97,24 @@ pub(crate) fn lint_semantic(db: &dyn LintDb, file_id: FileId) -> QueryResult<Dia
 
         lint_unresolved_imports(&context)?;
         lint_bad_overrides(&context)?;
+        lint_redundant_isinstance(&context)?;
+        lint_dunder_all_entries(&context)?;
+        lint_open_without_encoding(&context)?;
+        lint_slots_conflicts_with_default(&context)?;
+        lint_nan_comparison(&context)?;
+        lint_unsupported_in_operand(&context)?;
+        lint_dataclass_params(&context)?;
+        lint_pop_on_empty_literal(&context)?;
+        lint_final_override(&context)?;
+        lint_unreachable_loop_else(&context)?;
+        lint_abstract_instantiation(&context)?;
+        lint_assert_type(&context)?;
+        lint_finally_control_flow(&context)?;
+        lint_assert_never(&context)?;
+        lint_redundant_cast(&context)?;
+        lint_del_never_bound(&context)?;
+        lint_new_init_signature_mismatch(&context)?;
+        lint_literal_element_type_mismatch(&context)?;
 
         Ok(Diagnostics::from(context.diagnostics.take()))
     })
@@ -142,6 +161,690 @@ fn lint_unresolved_imports(context: &SemanticLintContext) -> QueryResult<()> {
     Ok(())
 }
 
+/// Flags entries in a module-level `__all__` list/tuple of string literals that don't correspond
+/// to any name defined or imported at module scope.
+///
+/// Non-literal entries (anything other than a bare string literal, e.g. a name or a call) are
+/// skipped rather than flagged, since we can't tell what they'd resolve to.
+fn lint_dunder_all_entries(context: &SemanticLintContext) -> QueryResult<()> {
+    let symbol_table = context.semantic_index().symbol_table();
+    let Some(all_symbol_id) = symbol_table.root_symbol_id_by_name("__all__") else {
+        return Ok(());
+    };
+
+    for definition in symbol_table.definitions(all_symbol_id) {
+        let Definition::Assignment(node_key) = definition else {
+            continue;
+        };
+        let Some(node) = node_key.resolve(context.ast().into()) else {
+            continue;
+        };
+        let elts = match node.value.as_ref() {
+            ast::Expr::List(ast::ExprList { elts, .. }) => elts,
+            ast::Expr::Tuple(ast::ExprTuple { elts, .. }) => elts,
+            _ => continue,
+        };
+
+        for elt in elts {
+            let ast::Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) = elt else {
+                continue;
+            };
+            let name = value.to_str();
+            if !symbol_table
+                .root_symbol_by_name(name)
+                .is_some_and(|symbol| symbol.is_defined())
+            {
+                context.push_diagnostic(format!(
+                    "`__all__` entry '{name}' does not correspond to a module-level name"
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Flags `open(path)` calls left in (the default) text mode without an explicit `encoding`,
+/// since the platform-default encoding Python falls back to otherwise is inconsistent across
+/// platforms (PEP 597).
+///
+/// This purely syntactic: it doesn't resolve `open` against the `builtins` module, so a
+/// same-named local function called `open` would also be flagged. It's also always-on; the repo
+/// has no lint-selection mechanism yet for the "opt-in" classification this check is meant to
+/// have, matching `flake8-encodings`.
+fn lint_open_without_encoding(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = OpenWithoutEncodingVisitor { context };
+    visitor.visit_body(&context.ast().body);
+    Ok(())
+}
+
+struct OpenWithoutEncodingVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+}
+
+impl<'ast> Visitor<'ast> for OpenWithoutEncodingVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if let ast::Expr::Call(call) = expr {
+            self.check_open_call(call);
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl OpenWithoutEncodingVisitor<'_, '_> {
+    fn check_open_call(&self, call: &ast::ExprCall) {
+        let ast::Expr::Name(ast::ExprName { id: func_name, .. }) = call.func.as_ref() else {
+            return;
+        };
+        if func_name != "open" {
+            return;
+        }
+
+        let mode = call
+            .arguments
+            .args
+            .get(1)
+            .or_else(|| call.arguments.find_keyword("mode").map(|kw| &kw.value));
+        let is_binary_mode = match mode {
+            Some(ast::Expr::StringLiteral(ast::ExprStringLiteral { value, .. })) => {
+                value.to_str().contains('b')
+            }
+            // Unknown (non-literal) mode: don't guess, don't flag.
+            Some(_) => return,
+            None => false,
+        };
+        if is_binary_mode {
+            return;
+        }
+
+        // `encoding` is `open`'s 4th positional parameter (`file, mode, buffering, encoding`),
+        // so `open(path, "r", -1, "utf-8")` supplies it positionally without a keyword.
+        let has_encoding = call.arguments.find_keyword("encoding").is_some()
+            || call.arguments.args.get(3).is_some();
+        if !has_encoding {
+            self.context.push_diagnostic(
+                "`open()` in text mode without an explicit `encoding` (try `encoding=\"utf-8\"`)"
+                    .to_string(),
+            );
+        }
+    }
+}
+
+/// Flags a class that both declares a name in `__slots__` and gives it a class-level default
+/// (e.g. `__slots__ = ("x",)` alongside `x = 0`), which raises a `ValueError` at class creation
+/// time in CPython. `__slots__` is recognized whether it's written as a single string (one
+/// slot), a list/tuple of names, or a dict mapping each name to a docstring.
+fn lint_slots_conflicts_with_default(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = SlotsConflictVisitor { context };
+    visitor.visit_body(&context.ast().body);
+    Ok(())
+}
+
+struct SlotsConflictVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+}
+
+impl<'ast> Visitor<'ast> for SlotsConflictVisitor<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        if let ast::Stmt::ClassDef(class_def) = stmt {
+            self.check_class_def(class_def);
+        }
+        ruff_python_ast::visitor::walk_stmt(self, stmt);
+    }
+}
+
+impl SlotsConflictVisitor<'_, '_> {
+    fn check_class_def(&self, class_def: &ast::StmtClassDef) {
+        let mut slot_names = Vec::new();
+        for stmt in &class_def.body {
+            let ast::Stmt::Assign(ast::StmtAssign { targets, value, .. }) = stmt else {
+                continue;
+            };
+            let [ast::Expr::Name(ast::ExprName { id, .. })] = targets.as_slice() else {
+                continue;
+            };
+            if id != "__slots__" {
+                continue;
+            }
+            // `__slots__` can be a single string (one slot, not an iterable of one-character
+            // slots -- easy to get wrong, since plain strings normally *are* iterated character
+            // by character), a list/tuple of names, or a dict mapping each name to a docstring.
+            match value.as_ref() {
+                ast::Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) => {
+                    slot_names.push(value.to_str().to_string());
+                }
+                ast::Expr::List(ast::ExprList { elts, .. })
+                | ast::Expr::Tuple(ast::ExprTuple { elts, .. }) => {
+                    for elt in elts {
+                        if let ast::Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) =
+                            elt
+                        {
+                            slot_names.push(value.to_str().to_string());
+                        }
+                    }
+                }
+                ast::Expr::Dict(ast::ExprDict { items, .. }) => {
+                    for item in items {
+                        if let Some(ast::Expr::StringLiteral(ast::ExprStringLiteral {
+                            value,
+                            ..
+                        })) = &item.key
+                        {
+                            slot_names.push(value.to_str().to_string());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        if slot_names.is_empty() {
+            return;
+        }
+
+        for stmt in &class_def.body {
+            let target_name = match stmt {
+                ast::Stmt::Assign(ast::StmtAssign { targets, .. }) => match targets.as_slice() {
+                    [ast::Expr::Name(ast::ExprName { id, .. })] => Some(id),
+                    _ => None,
+                },
+                ast::Stmt::AnnAssign(ast::StmtAnnAssign {
+                    target,
+                    value: Some(_),
+                    ..
+                }) => match target.as_ref() {
+                    ast::Expr::Name(ast::ExprName { id, .. }) => Some(id),
+                    _ => None,
+                },
+                _ => None,
+            };
+            let Some(target_name) = target_name else {
+                continue;
+            };
+            if target_name == "__slots__" {
+                continue;
+            }
+            if slot_names.iter().any(|slot| slot == target_name.as_str()) {
+                self.context.push_diagnostic(format!(
+                    "`{target_name}` is declared in `__slots__` but also given a class-level \
+                     default, which raises `ValueError` at class creation"
+                ));
+            }
+        }
+    }
+}
+
+/// Flags a class that defines both `__new__` and `__init__` with clearly-incompatible parameter
+/// lists. CPython calls both with the same arguments (`C(*args, **kwargs)` invokes
+/// `__new__(cls, *args, **kwargs)` then `__init__(self, *args, **kwargs)`), so a required
+/// parameter accepted by one but not the other is always a construction bug: either call fails
+/// for any arguments that would satisfy the other.
+///
+/// This only looks at parameter *names* declared directly in the class body, not types (there's
+/// no parameter-list representation on `FunctionType` to check types against yet, see its TODO
+/// in `types.rs`), and only when neither method has a `*args`/`**kwargs` catch-all, so it stays
+/// conservative: it won't flag a `__new__`/`__init__` pair that's actually compatible through
+/// `*args`/`**kwargs`, an inherited method, or a decorator that rewrites the signature.
+fn lint_new_init_signature_mismatch(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = NewInitSignatureVisitor { context };
+    visitor.visit_body(&context.ast().body);
+    Ok(())
+}
+
+struct NewInitSignatureVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+}
+
+impl<'ast> Visitor<'ast> for NewInitSignatureVisitor<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        if let ast::Stmt::ClassDef(class_def) = stmt {
+            self.check_class_def(class_def);
+        }
+        ruff_python_ast::visitor::walk_stmt(self, stmt);
+    }
+}
+
+impl NewInitSignatureVisitor<'_, '_> {
+    /// Required parameter names (no default) and all accepted parameter names, both excluding the
+    /// leading `cls`/`self`, or `None` if the method accepts `*args`/`**kwargs` and so can't be
+    /// meaningfully compared -- either one can transparently forward an argument the other side
+    /// declares as required.
+    fn param_names(parameters: &ast::Parameters) -> Option<(Vec<String>, Vec<String>)> {
+        if parameters.kwarg.is_some() || parameters.vararg.is_some() {
+            return None;
+        }
+        let params = || parameters.iter_non_variadic_params().skip(1);
+        Some((
+            params()
+                .filter(|param| param.default.is_none())
+                .map(|param| param.parameter.name.id.to_string())
+                .collect(),
+            params()
+                .map(|param| param.parameter.name.id.to_string())
+                .collect(),
+        ))
+    }
+
+    fn check_class_def(&self, class_def: &ast::StmtClassDef) {
+        let mut new_params = None;
+        let mut init_params = None;
+        for stmt in &class_def.body {
+            let ast::Stmt::FunctionDef(function_def) = stmt else {
+                continue;
+            };
+            match function_def.name.id.as_str() {
+                "__new__" => new_params = Some(&function_def.parameters),
+                "__init__" => init_params = Some(&function_def.parameters),
+                _ => {}
+            }
+        }
+        let (Some(new_params), Some(init_params)) = (new_params, init_params) else {
+            return;
+        };
+        let (Some((new_required, new_accepted)), Some((init_required, init_accepted))) = (
+            Self::param_names(new_params),
+            Self::param_names(init_params),
+        ) else {
+            return;
+        };
+        let new_only: Vec<_> = new_required
+            .iter()
+            .filter(|name| !init_accepted.contains(name))
+            .collect();
+        let init_only: Vec<_> = init_required
+            .iter()
+            .filter(|name| !new_accepted.contains(name))
+            .collect();
+        if !new_only.is_empty() {
+            self.context.push_diagnostic(format!(
+                "`{}.__new__` requires parameter(s) {} that `__init__` does not accept",
+                class_def.name,
+                new_only
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ));
+        }
+        if !init_only.is_empty() {
+            self.context.push_diagnostic(format!(
+                "`{}.__init__` requires parameter(s) {} that `__new__` does not accept",
+                class_def.name,
+                init_only
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ));
+        }
+    }
+}
+
+/// Flags `==`/`!=` comparisons against a statically-known-NaN operand (`float("nan")` or
+/// `math.nan`), which is always `False`/always `True` respectively -- NaN never compares equal
+/// to anything, including itself. Suggests `math.isnan` instead.
+///
+/// This is deliberately conservative: it only recognizes those two literal spellings of NaN, not
+/// arbitrary expressions that happen to evaluate to NaN at runtime.
+fn lint_nan_comparison(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = NanComparisonVisitor { context };
+    visitor.visit_body(&context.ast().body);
+    Ok(())
+}
+
+struct NanComparisonVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+}
+
+impl<'ast> Visitor<'ast> for NanComparisonVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if let ast::Expr::Compare(compare) = expr {
+            self.check_compare(compare);
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl NanComparisonVisitor<'_, '_> {
+    fn check_compare(&self, compare: &ast::ExprCompare) {
+        let has_eq_or_not_eq = compare
+            .ops
+            .iter()
+            .any(|op| matches!(op, ast::CmpOp::Eq | ast::CmpOp::NotEq));
+        if !has_eq_or_not_eq {
+            return;
+        }
+
+        let operands = std::iter::once(compare.left.as_ref()).chain(compare.comparators.iter());
+        if operands.into_iter().any(is_known_nan_expr) {
+            self.context.push_diagnostic(
+                "Comparing with NaN using `==`/`!=` is always false/true; use `math.isnan()` \
+                 instead"
+                    .to_string(),
+            );
+        }
+    }
+}
+
+fn is_known_nan_expr(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::Call(ast::ExprCall {
+            func, arguments, ..
+        }) => {
+            let ast::Expr::Name(ast::ExprName { id, .. }) = func.as_ref() else {
+                return false;
+            };
+            let [ast::Expr::StringLiteral(ast::ExprStringLiteral { value, .. })] =
+                arguments.args.as_ref()
+            else {
+                return false;
+            };
+            id == "float" && value.to_str().trim().eq_ignore_ascii_case("nan")
+        }
+        ast::Expr::Attribute(ast::ExprAttribute { value, attr, .. }) => {
+            let ast::Expr::Name(ast::ExprName { id, .. }) = value.as_ref() else {
+                return false;
+            };
+            id == "math" && attr.id == "nan"
+        }
+        _ => false,
+    }
+}
+
+/// Flags `x in y`/`x not in y` where `y`'s inferred type is statically known to have neither
+/// `__contains__`, `__iter__`, nor `__getitem__`, so the check always raises `TypeError` at
+/// runtime.
+///
+/// This is deliberately narrow: there's no dunder-dispatch machinery in `infer_expr_type` yet
+/// (see its `Compare` arm's TODO), so rather than resolving `y`'s class members to look for those
+/// three dunders, this only recognizes the handful of types that can never define them --
+/// `None` and a bare `int` literal -- and says nothing about any other (including unresolved or
+/// user-defined) type.
+fn lint_unsupported_in_operand(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = UnsupportedInOperandVisitor {
+        context,
+        result: Ok(()),
+    };
+    visitor.visit_body(&context.ast().body);
+    visitor.result
+}
+
+struct UnsupportedInOperandVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    result: QueryResult<()>,
+}
+
+impl<'ast> Visitor<'ast> for UnsupportedInOperandVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if self.result.is_ok() {
+            if let ast::Expr::Compare(compare) = expr {
+                if let Err(err) = self.check_compare(compare) {
+                    self.result = Err(err);
+                }
+            }
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl UnsupportedInOperandVisitor<'_, '_> {
+    fn check_compare(&self, compare: &ast::ExprCompare) -> QueryResult<()> {
+        let db = self.context.db.upcast();
+        let file_id = self.context.file_id();
+        let operands = std::iter::once(compare.left.as_ref()).chain(compare.comparators.iter());
+        for (op, right) in compare.ops.iter().zip(operands.skip(1)) {
+            if !matches!(op, ast::CmpOp::In | ast::CmpOp::NotIn) {
+                continue;
+            }
+            let right_ty = infer_expr_type(db, file_id, right)?;
+            if matches!(right_ty, Type::None | Type::IntLiteral(_)) {
+                self.context.push_diagnostic(format!(
+                    "Unsupported operand type for `{}`: `{}` is not iterable and has no \
+                     `__contains__`",
+                    if matches!(op, ast::CmpOp::In) {
+                        "in"
+                    } else {
+                        "not in"
+                    },
+                    describe_type_for_diagnostic(db, right_ty)?,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether `func` (a call's callee expression) refers to `name`, bare (`name(...)`) or
+/// qualified through an attribute access (`module.name(...)`) -- matching on the syntactic name
+/// only, with no regard for what it actually resolves to.
+fn is_call_to(func: &ast::Expr, name: &str) -> bool {
+    match func {
+        ast::Expr::Name(ast::ExprName { id, .. }) => id == name,
+        ast::Expr::Attribute(ast::ExprAttribute { attr, .. }) => attr.id == name,
+        _ => false,
+    }
+}
+
+/// Flags an invalid combination of `@dataclasses.dataclass(...)` keyword arguments, e.g.
+/// `order=True` without `eq=True` (ordering methods are generated from equality, so `ValueError`
+/// is raised at class-creation time if `eq` is explicitly disabled).
+///
+/// Only literal `True`/`False` keyword values are checked; anything else (a name, an expression)
+/// is left alone since we can't tell what it evaluates to.
+fn lint_dataclass_params(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = DataclassParamsVisitor { context };
+    visitor.visit_body(&context.ast().body);
+    Ok(())
+}
+
+struct DataclassParamsVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+}
+
+impl<'ast> Visitor<'ast> for DataclassParamsVisitor<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        if let ast::Stmt::ClassDef(class_def) = stmt {
+            for decorator in &class_def.decorator_list {
+                self.check_decorator(&decorator.expression);
+            }
+        }
+        ruff_python_ast::visitor::walk_stmt(self, stmt);
+    }
+}
+
+impl DataclassParamsVisitor<'_, '_> {
+    fn check_decorator(&self, decorator: &ast::Expr) {
+        let ast::Expr::Call(ast::ExprCall {
+            func, arguments, ..
+        }) = decorator
+        else {
+            return;
+        };
+        if !is_call_to(func, "dataclass") {
+            return;
+        }
+
+        let eq = arguments
+            .find_keyword("eq")
+            .and_then(|kw| literal_bool(&kw.value));
+        let order = arguments
+            .find_keyword("order")
+            .and_then(|kw| literal_bool(&kw.value));
+
+        if order == Some(true) && eq == Some(false) {
+            self.context.push_diagnostic(
+                "`@dataclass(order=True, eq=False)` raises `ValueError`: ordering requires `eq`"
+                    .to_string(),
+            );
+        }
+    }
+}
+
+// TODO `@dataclass` classes don't get a synthesized `__init__` signature at all right now --
+// `check_decorator` above only inspects the decorator's own keyword arguments, never the class
+// body's field assignments. Respecting `field(init=False)` (excluding that field from the
+// synthesized constructor's parameters, and optionally warning when it has neither a
+// `default`/`default_factory` nor an assignment in `__post_init__`) needs: (1) recognizing
+// `dataclasses.field(...)` calls as field specifiers in the first place, (2) a place to put a
+// synthesized `__init__` member on the class (there's no mechanism for synthesized/generated
+// methods at all -- `ClassType`'s members all come from the literal class body), and (3) a
+// parameter list on `FunctionType` to synthesize into, which doesn't exist either. None of this
+// is reachable without that scaffolding, so for now `init=False` fields are just ordinary class
+// attributes as far as this crate is concerned.
+fn literal_bool(expr: &ast::Expr) -> Option<bool> {
+    match expr {
+        ast::Expr::BooleanLiteral(ast::ExprBooleanLiteral { value, .. }) => Some(*value),
+        _ => None,
+    }
+}
+
+/// Flags `.pop()` with no default argument called directly on a freshly-created empty list or
+/// dict literal (`[].pop()`, `{}.pop(key)`), which always raises at runtime. Opt-in in spirit
+/// (scoped to the obviously-empty case), though the repo has no lint-selection mechanism yet to
+/// actually make it opt-in.
+///
+/// This is deliberately narrow: it only looks at a literal receiver written directly at the call
+/// site, not emptiness tracked through a variable.
+fn lint_pop_on_empty_literal(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = PopOnEmptyLiteralVisitor { context };
+    visitor.visit_body(&context.ast().body);
+    Ok(())
+}
+
+struct PopOnEmptyLiteralVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+}
+
+impl<'ast> Visitor<'ast> for PopOnEmptyLiteralVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if let ast::Expr::Call(call) = expr {
+            self.check_pop_call(call);
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl PopOnEmptyLiteralVisitor<'_, '_> {
+    fn check_pop_call(&self, call: &ast::ExprCall) {
+        let ast::Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = call.func.as_ref()
+        else {
+            return;
+        };
+        if attr.id != "pop" {
+            return;
+        }
+        // `list.pop()` takes an optional index (never a default), so only the bare no-argument
+        // call always raises on empty. `dict.pop(key)` takes a required key plus an optional
+        // default, so it's the one-argument (key only, no default) call that always raises.
+        let always_raises = match value.as_ref() {
+            ast::Expr::List(ast::ExprList { elts, .. }) => {
+                elts.is_empty() && call.arguments.args.is_empty()
+            }
+            ast::Expr::Dict(ast::ExprDict { items, .. }) => {
+                items.is_empty() && call.arguments.args.len() == 1
+            }
+            _ => false,
+        };
+        if !always_raises || !call.arguments.keywords.is_empty() {
+            return;
+        }
+
+        self.context.push_diagnostic(
+            "`.pop()` on a freshly-created empty literal always raises; pass a default"
+                .to_string(),
+        );
+    }
+}
+
+/// Flags a `for`/`while` loop's `else` clause when the loop body contains no `break`: the `else`
+/// then always runs (once the loop finishes or is skipped entirely), making the `else` redundant
+/// -- the same code could just be unindented to follow the loop directly. For a `while True:`
+/// loop specifically, having no `break` means the loop never exits normally at all, so the `else`
+/// is not just redundant but unreachable.
+///
+/// This only looks for a `break` textually inside the loop body; it doesn't check whether that
+/// `break` is actually reachable (e.g. one guarded by `if False:`), so a present `break` always
+/// suppresses the diagnostic: one that can statically never run hides a redundant `else` rather
+/// than causing a false report.
+fn lint_unreachable_loop_else(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = LoopElseVisitor { context };
+    visitor.visit_body(&context.ast().body);
+    Ok(())
+}
+
+struct LoopElseVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+}
+
+impl<'ast> Visitor<'ast> for LoopElseVisitor<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        match stmt {
+            ast::Stmt::While(ast::StmtWhile {
+                test, body, orelse, ..
+            }) => {
+                self.check_loop_else(body, orelse, literal_bool(test) == Some(true));
+            }
+            ast::Stmt::For(ast::StmtFor { body, orelse, .. }) => {
+                self.check_loop_else(body, orelse, false);
+            }
+            _ => {}
+        }
+        ruff_python_ast::visitor::walk_stmt(self, stmt);
+    }
+}
+
+impl LoopElseVisitor<'_, '_> {
+    fn check_loop_else(&self, body: &[ast::Stmt], orelse: &[ast::Stmt], is_infinite: bool) {
+        if orelse.is_empty() || has_break(body) {
+            return;
+        }
+        if is_infinite {
+            self.context.push_diagnostic(
+                "unreachable `else` clause: this loop has no `break`, so it never exits normally"
+                    .to_string(),
+            );
+        } else {
+            self.context.push_diagnostic(
+                "redundant `else` clause: this loop has no `break`, so the `else` always runs"
+                    .to_string(),
+            );
+        }
+    }
+}
+
+/// Returns whether `body` contains a `break` that would apply to the loop `body` belongs to
+/// (i.e. not one nested inside a further loop or a function/class body of its own).
+fn has_break(body: &[ast::Stmt]) -> bool {
+    let mut finder = BreakFinder { found: false };
+    finder.visit_body(body);
+    finder.found
+}
+
+struct BreakFinder {
+    found: bool,
+}
+
+impl<'ast> Visitor<'ast> for BreakFinder {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        if self.found {
+            return;
+        }
+        match stmt {
+            ast::Stmt::Break(_) => self.found = true,
+            // A `break` inside a nested loop or function/class body belongs to that construct,
+            // not the loop we're checking.
+            ast::Stmt::While(_)
+            | ast::Stmt::For(_)
+            | ast::Stmt::FunctionDef(_)
+            | ast::Stmt::ClassDef(_) => {}
+            _ => ruff_python_ast::visitor::walk_stmt(self, stmt),
+        }
+    }
+}
+
+/// Flags a method decorated `@typing.override` that doesn't actually override anything: no base
+/// class in its containing class's MRO defines a method of the same name (PEP 698). Catches
+/// typos in the method name and methods left behind after a base class is refactored out from
+/// under them.
 fn lint_bad_overrides(context: &SemanticLintContext) -> QueryResult<()> {
     // TODO we should have a special marker on the real typing module (from typeshed) so if you
     // have your own "typing" module in your project, we don't consider it THE typing module (and
@@ -153,44 +856,697 @@ fn lint_bad_overrides(context: &SemanticLintContext) -> QueryResult<()> {
         return Ok(());
     };
 
-    // TODO we should maybe index definitions by type instead of iterating all, or else iterate all
-    // just once, match, and branch to all lint rules that care about a type of definition
-    for (symbol, definition) in context.semantic_index().symbol_table().all_definitions() {
-        if !matches!(definition, Definition::FunctionDef(_)) {
-            continue;
+    // TODO we should maybe index definitions by type instead of iterating all, or else iterate all
+    // just once, match, and branch to all lint rules that care about a type of definition
+    for (symbol, definition) in context.semantic_index().symbol_table().all_definitions() {
+        if !matches!(definition, Definition::FunctionDef(_)) {
+            continue;
+        }
+        let ty = infer_definition_type(
+            context.db.upcast(),
+            GlobalSymbolId {
+                file_id: context.file_id,
+                symbol_id: symbol,
+            },
+            definition.clone(),
+        )?;
+        let Type::Function(func) = ty else {
+            unreachable!("type of a FunctionDef should always be a Function");
+        };
+        let Some(class) = func.get_containing_class(context.db.upcast())? else {
+            // not a method of a class
+            continue;
+        };
+        if func.has_decorator(context.db.upcast(), typing_override)? {
+            let method_name = func.name(context.db.upcast())?;
+            if class
+                .get_super_class_member(context.db.upcast(), &method_name)?
+                .is_none()
+            {
+                // TODO should have a qualname() method to support nested classes
+                context.push_diagnostic(
+                    format!(
+                        "Method {}.{} is decorated with `typing.override` but does not override any base class method",
+                        class.name(context.db.upcast())?,
+                        method_name,
+                    ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Flags a subclass method that overrides a base class method decorated `@typing.final`, which
+/// isn't allowed to be overridden.
+fn lint_final_override(context: &SemanticLintContext) -> QueryResult<()> {
+    let Some(typing_final) =
+        context.resolve_global_symbol(&ModuleName::new_static("typing").unwrap(), "final")?
+    else {
+        return Ok(());
+    };
+
+    for (symbol, definition) in context.semantic_index().symbol_table().all_definitions() {
+        if !matches!(definition, Definition::FunctionDef(_)) {
+            continue;
+        }
+        let ty = infer_definition_type(
+            context.db.upcast(),
+            GlobalSymbolId {
+                file_id: context.file_id,
+                symbol_id: symbol,
+            },
+            definition.clone(),
+        )?;
+        let Type::Function(func) = ty else {
+            unreachable!("type of a FunctionDef should always be a Function");
+        };
+        let Some(class) = func.get_containing_class(context.db.upcast())? else {
+            continue;
+        };
+        let method_name = func.name(context.db.upcast())?;
+        let Some(Type::Function(base_func)) =
+            class.get_super_class_member(context.db.upcast(), &method_name)?
+        else {
+            continue;
+        };
+        if base_func.has_decorator(context.db.upcast(), typing_final)? {
+            context.push_diagnostic(format!(
+                "Method {}.{} overrides a method decorated `@typing.final`, which cannot be \
+                 overridden",
+                class.name(context.db.upcast())?,
+                method_name,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Flags instantiating a class (`Foo()`) that still has one or more `@abstractmethod`-decorated
+/// methods in its MRO without a concrete override, naming which methods remain unimplemented.
+///
+/// The class is taken from the callee's inferred type, so a call through a module-level name the
+/// class was assigned to (`Alias = Foo; Alias()`) is recognized as well; instantiation through a
+/// factory function is not.
+fn lint_abstract_instantiation(context: &SemanticLintContext) -> QueryResult<()> {
+    let Some(abstractmethod) =
+        context.resolve_global_symbol(&ModuleName::new_static("abc").unwrap(), "abstractmethod")?
+    else {
+        return Ok(());
+    };
+    let mut visitor = AbstractInstantiationVisitor {
+        context,
+        abstractmethod,
+        result: Ok(()),
+    };
+    visitor.visit_body(&context.ast().body);
+    visitor.result
+}
+
+struct AbstractInstantiationVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    abstractmethod: GlobalSymbolId,
+    result: QueryResult<()>,
+}
+
+impl<'ast> Visitor<'ast> for AbstractInstantiationVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if self.result.is_ok() {
+            if let ast::Expr::Call(call) = expr {
+                if let Err(err) = self.check_call(call) {
+                    self.result = Err(err);
+                }
+            }
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl AbstractInstantiationVisitor<'_, '_> {
+    fn check_call(&self, call: &ast::ExprCall) -> QueryResult<()> {
+        let Type::Class(class) =
+            infer_expr_type(self.context.db.upcast(), self.context.file_id(), &call.func)?
+        else {
+            return Ok(());
+        };
+        let unimplemented =
+            class.unimplemented_abstract_methods(self.context.db.upcast(), self.abstractmethod)?;
+        if !unimplemented.is_empty() {
+            self.context.push_diagnostic(format!(
+                "Cannot instantiate abstract class `{}` with unimplemented abstract method{} {}",
+                class.name(self.context.db.upcast())?,
+                if unimplemented.len() == 1 { "" } else { "s" },
+                unimplemented
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// TODO a lint for unreachable `match` case arms (flagging a `case` whose pattern can never match
+// because earlier arms already exhausted the subject's narrowed type, e.g. matching every member
+// of an enum or `Literal` union) needs `match` to be a typed construct in the first place, which
+// it isn't: there's no `infer_match_statement`-style query, `Match` isn't specially indexed in
+// `SemanticIndexer::visit_stmt` (see the TODO there), and case patterns have no inferred type to
+// track narrowing the subject down to `Never` with. All of that would need to exist before this
+// lint has anything to walk.
+
+// TODO there's no `reveal_type`/`reveal_locals` debugging-aid support at all yet, not even the
+// `reveal_type(x)` single-expression form this would build on. Either name could be recognized
+// syntactically (as `is_call_to` here and `called_function_name` in `infer.rs` do for other
+// callables), but there's no informational-diagnostic severity to distinguish a
+// "here's what I inferred" message from an actual problem: `SemanticLintContext::push_diagnostic`
+// takes a bare `String` with no severity at all. `reveal_locals()` specifically would also need a
+// way to ask the use-def map "every binding visible at this flow node", which only exists today
+// in the single-symbol-at-a-time form `infer_symbol_public_type`/`infer_definition_type` use,
+// not an enumerate-everything-reachable-here query.
+
+/// Flags `isinstance(x, C)` checks where `x` is already narrowed to (exactly) an instance of
+/// `C` at that point, making the check always `True` and redundant.
+///
+/// This is deliberately conservative: it only fires when the narrowed type is a single,
+/// known class instance, never for `Any`/`Unknown`, unions, or unresolved names.
+fn lint_redundant_isinstance(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = RedundantIsinstanceVisitor {
+        context,
+        result: Ok(()),
+    };
+    visitor.visit_body(&context.ast().body);
+    visitor.result
+}
+
+struct RedundantIsinstanceVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    result: QueryResult<()>,
+}
+
+impl<'ast> Visitor<'ast> for RedundantIsinstanceVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if self.result.is_ok() {
+            if let ast::Expr::Call(call) = expr {
+                if let Err(err) = self.check_isinstance_call(call) {
+                    self.result = Err(err);
+                }
+            }
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl RedundantIsinstanceVisitor<'_, '_> {
+    fn check_isinstance_call(&self, call: &ast::ExprCall) -> QueryResult<()> {
+        let ast::Expr::Name(ast::ExprName { id: func_name, .. }) = call.func.as_ref() else {
+            return Ok(());
+        };
+        if func_name != "isinstance" || call.arguments.args.len() != 2 {
+            return Ok(());
+        }
+        let subject = &call.arguments.args[0];
+        let ast::Expr::Name(ast::ExprName { .. }) = subject else {
+            return Ok(());
+        };
+
+        let narrowed_ty =
+            infer_expr_type(self.context.db.upcast(), self.context.file_id, subject)?;
+        let Type::Instance(narrowed_class) = narrowed_ty else {
+            // Not yet narrowed to a single known class (e.g. `Any`, `Unknown`, a union, or
+            // the name is still unbound) -- never flag in that case.
+            return Ok(());
+        };
+
+        if let Type::Class(checked_class) =
+            infer_expr_type(self.context.db.upcast(), self.context.file_id, &call.arguments.args[1])?
+        {
+            if checked_class == narrowed_class {
+                self.context.push_diagnostic(
+                    "Redundant `isinstance` check: the subject is already narrowed to this type"
+                        .to_string(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Flags `typing.assert_type(expr, T)` calls where `expr`'s inferred type is not exactly `T` --
+/// using equivalence, not assignability, matching `typing.assert_type`'s own strict semantics.
+///
+/// This only compares the case where both the actual and expected types are a plain class
+/// instance (`int`, `MyClass`, ...); anything else (a union, `Any`/`Unknown`, a subscripted or
+/// unioned `T`) can't be evaluated to a type precise enough to compare without a false positive,
+/// so those calls are left unchecked rather than risking one.
+fn lint_assert_type(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = AssertTypeVisitor {
+        context,
+        result: Ok(()),
+    };
+    visitor.visit_body(&context.ast().body);
+    visitor.result
+}
+
+struct AssertTypeVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    result: QueryResult<()>,
+}
+
+impl<'ast> Visitor<'ast> for AssertTypeVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if self.result.is_ok() {
+            if let ast::Expr::Call(call) = expr {
+                if let Err(err) = self.check_call(call) {
+                    self.result = Err(err);
+                }
+            }
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl AssertTypeVisitor<'_, '_> {
+    fn check_call(&self, call: &ast::ExprCall) -> QueryResult<()> {
+        if !is_call_to(&call.func, "assert_type") || call.arguments.args.len() != 2 {
+            return Ok(());
+        }
+
+        let db = self.context.db.upcast();
+        let file_id = self.context.file_id();
+        let Type::Instance(actual_class) = infer_expr_type(db, file_id, &call.arguments.args[0])?
+        else {
+            return Ok(());
+        };
+        let Type::Class(expected_class) = infer_expr_type(db, file_id, &call.arguments.args[1])?
+        else {
+            return Ok(());
+        };
+
+        if actual_class != expected_class {
+            self.context.push_diagnostic(format!(
+                "Expected type `{}`, got type `{}` instead",
+                expected_class.name(db)?,
+                actual_class.name(db)?,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Flags a `return`/`break`/`continue` that runs directly inside a `finally` block (not inside a
+/// function/class body nested within it, and for `break`/`continue`, not inside a loop that
+/// itself starts within the `finally` block). Any of these unconditionally discard a pending
+/// exception, or override whatever the `try`/`except` clauses were about to return, which is
+/// almost always a bug. Opt-in in spirit, as with the other lints in this module -- there's no
+/// lint-selection mechanism yet to make that configurable.
+///
+/// This is deliberately simple about nesting: a `finally` block containing its own nested `try`
+/// with a `finally` of its own may be visited twice (once as part of the outer scan, once on its
+/// own), which can double-report the same statement. That's noisy but not incorrect -- the
+/// statement really does run inside both `finally` blocks.
+///
+/// This lint is deliberately unconditional about whether the corresponding `try` body can
+/// actually raise -- a more targeted version would only fire when it provably can (and skip a
+/// `try` body that's "clean", e.g. only assignments with no calls), which needs knowing which
+/// statements can raise at all. There's no such analysis anywhere in this crate: every statement
+/// kind is treated as equally capable of raising (or not raising) by every lint and type query
+/// here, so there's nothing to narrow "unconditional" down to "only when something could actually
+/// be pending". Until that exists, this fires on every `return`/`break`/`continue` in a `finally`
+/// rather than risking false negatives on the `try` bodies it can't prove are exception-free.
+fn lint_finally_control_flow(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = FinallyControlFlowVisitor { context };
+    visitor.visit_body(&context.ast().body);
+    Ok(())
+}
+
+struct FinallyControlFlowVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+}
+
+impl<'ast> Visitor<'ast> for FinallyControlFlowVisitor<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        if let ast::Stmt::Try(ast::StmtTry { finalbody, .. }) = stmt {
+            let mut finder = FinallyExitFinder {
+                context: self.context,
+                loop_depth: 0,
+            };
+            finder.visit_body(finalbody);
+        }
+        ruff_python_ast::visitor::walk_stmt(self, stmt);
+    }
+}
+
+struct FinallyExitFinder<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    loop_depth: u32,
+}
+
+impl<'ast> Visitor<'ast> for FinallyExitFinder<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        match stmt {
+            ast::Stmt::Return(_) => {
+                self.context.push_diagnostic(
+                    "`return` inside a `finally` block unconditionally discards any pending \
+                     exception (and overrides whatever the `try`/`except` was about to return)"
+                        .to_string(),
+                );
+            }
+            ast::Stmt::Break(_) if self.loop_depth == 0 => {
+                self.context.push_diagnostic(
+                    "`break` inside a `finally` block unconditionally discards any pending \
+                     exception"
+                        .to_string(),
+                );
+            }
+            ast::Stmt::Continue(_) if self.loop_depth == 0 => {
+                self.context.push_diagnostic(
+                    "`continue` inside a `finally` block unconditionally discards any pending \
+                     exception"
+                        .to_string(),
+                );
+            }
+            ast::Stmt::While(ast::StmtWhile { body, orelse, .. })
+            | ast::Stmt::For(ast::StmtFor { body, orelse, .. }) => {
+                self.loop_depth += 1;
+                self.visit_body(body);
+                self.loop_depth -= 1;
+                self.visit_body(orelse);
+            }
+            // A nested function/class body's own control-flow statements target that scope, not
+            // this `finally` block.
+            ast::Stmt::FunctionDef(_) | ast::Stmt::ClassDef(_) => {}
+            _ => ruff_python_ast::visitor::walk_stmt(self, stmt),
+        }
+    }
+}
+
+/// Flags `typing.assert_never(x)` where `x`'s inferred type at that point is not `Type::Never`,
+/// naming the types that still need to be handled -- the standard idiom for exhaustiveness
+/// checking, combined with narrowing in preceding `if`/`elif` branches.
+///
+/// Narrowing is limited today (see the TODOs throughout `infer_constraint_type`), so this will
+/// flag plenty of `assert_never` calls that a more complete type checker would accept. It also
+/// reports nothing when the type is `Any`/`Unknown` or a union containing either, so a real
+/// exhaustiveness gap on such a type goes unreported.
+fn lint_assert_never(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = AssertNeverVisitor {
+        context,
+        result: Ok(()),
+    };
+    visitor.visit_body(&context.ast().body);
+    visitor.result
+}
+
+struct AssertNeverVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    result: QueryResult<()>,
+}
+
+impl<'ast> Visitor<'ast> for AssertNeverVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if self.result.is_ok() {
+            if let ast::Expr::Call(call) = expr {
+                if let Err(err) = self.check_call(call) {
+                    self.result = Err(err);
+                }
+            }
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl AssertNeverVisitor<'_, '_> {
+    fn check_call(&self, call: &ast::ExprCall) -> QueryResult<()> {
+        if !is_call_to(&call.func, "assert_never") || call.arguments.args.len() != 1 {
+            return Ok(());
+        }
+
+        let db = self.context.db.upcast();
+        let ty = infer_expr_type(db, self.context.file_id(), &call.arguments.args[0])?;
+        if matches!(ty, Type::Never) {
+            return Ok(());
+        }
+
+        let members = if let Type::Union(union_id) = ty {
+            let jar: &SemanticJar = db.jar()?;
+            union_id.elements(&jar.type_store)
+        } else {
+            vec![ty]
+        };
+        if members
+            .iter()
+            .any(|member| matches!(member, Type::Any | Type::Unknown))
+        {
+            // Not yet narrowed enough to be sure `Never` wasn't reached (e.g. `Any`/`Unknown`, or
+            // a union containing either) -- never flag in that case, matching
+            // `lint_redundant_isinstance`'s and `lint_assert_type`'s convention of failing silent
+            // on imprecise types rather than false-positiving on them.
+            return Ok(());
+        }
+        let mut names = Vec::with_capacity(members.len());
+        for member in members {
+            names.push(describe_type_for_diagnostic(db, member)?);
+        }
+
+        self.context.push_diagnostic(format!(
+            "`assert_never` reached with a type that isn't narrowed to `Never`; unhandled: {}",
+            names.join(", "),
+        ));
+
+        Ok(())
+    }
+}
+
+/// Flags `del x` where `x` has no reaching binding at that point: a plain name (not an attribute
+/// or subscript target, which always go through `__delattr__`/`__delitem__` at runtime and so
+/// can't be "unbound" the same way) whose inferred type is exactly `Unbound` raises
+/// `NameError`/`UnboundLocalError` at runtime the moment CPython reaches it; one whose type is a
+/// `Union` containing `Unbound` alongside other members is reported as one that may raise.
+fn lint_del_never_bound(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = DelNeverBoundVisitor {
+        context,
+        result: Ok(()),
+    };
+    visitor.visit_body(&context.ast().body);
+    visitor.result
+}
+
+struct DelNeverBoundVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    result: QueryResult<()>,
+}
+
+impl<'ast> Visitor<'ast> for DelNeverBoundVisitor<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        if self.result.is_ok() {
+            if let ast::Stmt::Delete(delete) = stmt {
+                if let Err(err) = self.check_delete(delete) {
+                    self.result = Err(err);
+                }
+            }
+        }
+        ruff_python_ast::visitor::walk_stmt(self, stmt);
+    }
+}
+
+impl DelNeverBoundVisitor<'_, '_> {
+    fn check_delete(&self, delete: &ast::StmtDelete) -> QueryResult<()> {
+        let db = self.context.db.upcast();
+        for target in &delete.targets {
+            let ast::Expr::Name(ast::ExprName { id, .. }) = target else {
+                continue;
+            };
+            let ty = infer_expr_type(db, self.context.file_id(), target)?;
+            let is_always_unbound = ty.is_unbound();
+            let is_possibly_unbound = if let Type::Union(union_id) = ty {
+                let jar: &SemanticJar = db.jar()?;
+                union_id
+                    .elements(&jar.type_store)
+                    .iter()
+                    .any(Type::is_unbound)
+            } else {
+                false
+            };
+            if is_always_unbound {
+                self.context.push_diagnostic(format!(
+                    "`del {id}` of a name that has no reaching binding here; this raises \
+                     `NameError`/`UnboundLocalError` at runtime"
+                ));
+            } else if is_possibly_unbound {
+                self.context.push_diagnostic(format!(
+                    "`del {id}` may raise `UnboundLocalError`: `{id}` is possibly unbound here"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A best-effort, human-readable name for a `Type`, for diagnostics that don't have access to
+/// the real `Type::display` (which needs a `TypeStore` reference, not just a `SemanticDb`).
+fn describe_type_for_diagnostic(db: &dyn SemanticDb, ty: Type) -> QueryResult<String> {
+    Ok(match ty {
+        Type::Instance(class_id) => class_id.name(db)?.to_string(),
+        Type::Class(class_id) => format!("type[{}]", class_id.name(db)?),
+        Type::None => "None".to_string(),
+        Type::IntLiteral(n) => format!("Literal[{n}]"),
+        Type::Any => "Any".to_string(),
+        Type::Unbound => "Unbound".to_string(),
+        _ => "<type>".to_string(),
+    })
+}
+
+/// Flags `typing.cast(T, x)` where `x`'s inferred type is already `T`, making the cast redundant
+/// (mirrors mypy's `--warn-redundant-casts`).
+///
+/// Like `lint_assert_type`, this only compares the case where both the cast target and `x`'s
+/// inferred type are a plain class instance; it never fires when `x` infers as `Any`/`Unknown`,
+/// since a cast from a dynamic type is meaningful even when the target happens to match the
+/// runtime type.
+fn lint_redundant_cast(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = RedundantCastVisitor {
+        context,
+        result: Ok(()),
+    };
+    visitor.visit_body(&context.ast().body);
+    visitor.result
+}
+
+struct RedundantCastVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    result: QueryResult<()>,
+}
+
+impl<'ast> Visitor<'ast> for RedundantCastVisitor<'_, '_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        if self.result.is_ok() {
+            if let ast::Expr::Call(call) = expr {
+                if let Err(err) = self.check_call(call) {
+                    self.result = Err(err);
+                }
+            }
+        }
+        ruff_python_ast::visitor::walk_expr(self, expr);
+    }
+}
+
+impl RedundantCastVisitor<'_, '_> {
+    fn check_call(&self, call: &ast::ExprCall) -> QueryResult<()> {
+        if !is_call_to(&call.func, "cast") || call.arguments.args.len() != 2 {
+            return Ok(());
+        }
+
+        let db = self.context.db.upcast();
+        let file_id = self.context.file_id();
+        let Type::Class(target_class) = infer_expr_type(db, file_id, &call.arguments.args[0])?
+        else {
+            return Ok(());
+        };
+        let Type::Instance(source_class) = infer_expr_type(db, file_id, &call.arguments.args[1])?
+        else {
+            return Ok(());
+        };
+
+        if target_class == source_class {
+            self.context.push_diagnostic(format!(
+                "Redundant `cast`: the argument is already of type `{}`",
+                target_class.name(db)?,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Flags an element of a `list`/`set` literal assigned to an annotated target (`x: list[C] =
+/// [...]`) whose inferred type doesn't match the annotation's element type `C`, naming the
+/// specific offending element rather than only reporting that the literal as a whole is
+/// incompatible.
+///
+/// This is deliberately narrow in three ways. First, only `list[C]`/`set[C]` annotations are
+/// recognized (a bare subscript of a literal `list`/`set` name); `dict[K, V]`, `tuple[...]`, and
+/// anything spelled through an alias are left unchecked. Second, `C` itself must resolve to a
+/// plain user-defined class reference (`Type::Class`) -- a builtin element type like `list[int]`
+/// can't be checked at all yet, since there's no builtins/typeshed resolution anywhere in this
+/// crate to recognize a bare `int`/`str`/etc. as a builtin class in the first place (the same gap
+/// `infer_expr_type`'s `Expr::Call` arm documents for `str.encode`/`bytes.decode` and friends).
+/// Third, an element's own type must resolve to `Type::Instance` to be compared at all, so a
+/// literal made of e.g. string/bytes/bool literals (which currently infer as `Unknown`) is never
+/// flagged, only ever missed.
+fn lint_literal_element_type_mismatch(context: &SemanticLintContext) -> QueryResult<()> {
+    let mut visitor = LiteralElementTypeVisitor {
+        context,
+        result: Ok(()),
+    };
+    visitor.visit_body(&context.ast().body);
+    visitor.result
+}
+
+struct LiteralElementTypeVisitor<'ctx, 'src> {
+    context: &'ctx SemanticLintContext<'src>,
+    result: QueryResult<()>,
+}
+
+impl<'ast> Visitor<'ast> for LiteralElementTypeVisitor<'_, '_> {
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        if self.result.is_ok() {
+            if let ast::Stmt::AnnAssign(ann_assign) = stmt {
+                if let Err(err) = self.check_ann_assign(ann_assign) {
+                    self.result = Err(err);
+                }
+            }
         }
-        let ty = infer_definition_type(
-            context.db.upcast(),
-            GlobalSymbolId {
-                file_id: context.file_id,
-                symbol_id: symbol,
-            },
-            definition.clone(),
-        )?;
-        let Type::Function(func) = ty else {
-            unreachable!("type of a FunctionDef should always be a Function");
+        ruff_python_ast::visitor::walk_stmt(self, stmt);
+    }
+}
+
+impl LiteralElementTypeVisitor<'_, '_> {
+    fn check_ann_assign(&self, ann_assign: &ast::StmtAnnAssign) -> QueryResult<()> {
+        let Some(value) = ann_assign.value.as_ref() else {
+            return Ok(());
         };
-        let Some(class) = func.get_containing_class(context.db.upcast())? else {
-            // not a method of a class
-            continue;
+        let elts = match value.as_ref() {
+            ast::Expr::List(ast::ExprList { elts, .. }) => elts,
+            ast::Expr::Set(ast::ExprSet { elts, .. }) => elts,
+            _ => return Ok(()),
         };
-        if func.has_decorator(context.db.upcast(), typing_override)? {
-            let method_name = func.name(context.db.upcast())?;
-            if class
-                .get_super_class_member(context.db.upcast(), &method_name)?
-                .is_none()
-            {
-                // TODO should have a qualname() method to support nested classes
-                context.push_diagnostic(
-                    format!(
-                        "Method {}.{} is decorated with `typing.override` but does not override any base class method",
-                        class.name(context.db.upcast())?,
-                        method_name,
-                    ));
+
+        let ast::Expr::Subscript(ast::ExprSubscript { value: base, slice, .. }) =
+            ann_assign.annotation.as_ref()
+        else {
+            return Ok(());
+        };
+        let ast::Expr::Name(ast::ExprName { id: base_name, .. }) = base.as_ref() else {
+            return Ok(());
+        };
+        if base_name != "list" && base_name != "set" {
+            return Ok(());
+        }
+
+        let db = self.context.db.upcast();
+        let file_id = self.context.file_id();
+        let Type::Class(expected_class) = infer_expr_type(db, file_id, slice)? else {
+            return Ok(());
+        };
+
+        for elt in elts {
+            let Type::Instance(actual_class) = infer_expr_type(db, file_id, elt)? else {
+                continue;
+            };
+            if actual_class != expected_class {
+                self.context.push_diagnostic(format!(
+                    "Element has type `{}`, expected `{}` (from the annotation `{base_name}[...]`)",
+                    actual_class.name(db)?,
+                    expected_class.name(db)?,
+                ));
             }
         }
+
+        Ok(())
     }
-    Ok(())
 }
 
 pub struct SemanticLintContext<'a> {
@@ -256,7 +1612,7 @@ struct SyntaxLintVisitor<'a> {
     source: &'a str,
 }
 
-impl Visitor<'_> for SyntaxLintVisitor<'_> {
+impl<'ast> Visitor<'ast> for SyntaxLintVisitor<'_> {
     fn visit_string_literal(&mut self, string_literal: &'_ StringLiteral) {
         // A very naive implementation of use double quotes
         let text = &self.source[string_literal.range];
@@ -266,6 +1622,23 @@ impl Visitor<'_> for SyntaxLintVisitor<'_> {
                 .push("Use double quotes for strings".to_string());
         }
     }
+
+    fn visit_stmt(&mut self, stmt: &'ast ast::Stmt) {
+        if let ast::Stmt::Assert(ast::StmtAssert { test, .. }) = stmt {
+            // `assert (cond, "message")` is always truthy (a non-empty tuple), almost certainly
+            // meant as `assert cond, "message"`.
+            if let ast::Expr::Tuple(ast::ExprTuple { elts, .. }) = test.as_ref() {
+                if !elts.is_empty() {
+                    self.diagnostics.push(
+                        "Assert statement on a non-empty tuple is always true; did you mean \
+                         `assert cond, msg`?"
+                            .to_string(),
+                    );
+                }
+            }
+        }
+        ruff_python_ast::visitor::walk_stmt(self, stmt);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -333,3 +1706,842 @@ impl DerefMut for LintSemanticStorage {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::db::tests::TestDb;
+    use crate::db::SourceDb;
+    use crate::module::{set_module_search_paths, ModuleResolutionInputs};
+
+    use super::{lint_semantic, lint_syntax};
+
+    struct TestCase {
+        temp_dir: tempfile::TempDir,
+        db: TestDb,
+        src: PathBuf,
+    }
+
+    fn create_test() -> std::io::Result<TestCase> {
+        let temp_dir = tempfile::tempdir()?;
+
+        let src = temp_dir.path().join("src");
+        std::fs::create_dir(&src)?;
+        let src = src.canonicalize()?;
+
+        let search_paths = ModuleResolutionInputs {
+            extra_paths: vec![],
+            workspace_root: src.clone(),
+            site_packages: None,
+            custom_typeshed: None,
+        };
+
+        let mut db = TestDb::default();
+        set_module_search_paths(&mut db, search_paths);
+
+        Ok(TestCase { temp_dir, db, src })
+    }
+
+    fn write_to_path(case: &TestCase, relative_path: &str, contents: &str) -> anyhow::Result<()> {
+        let path = case.src.join(relative_path);
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    fn lint_errors(case: &TestCase, relative_path: &str) -> anyhow::Result<Vec<String>> {
+        let file_id = case.db.file_id(&case.src.join(relative_path));
+        Ok(lint_semantic(&case.db, file_id)?.as_slice().to_vec())
+    }
+
+    fn lint_syntax_errors(case: &TestCase, relative_path: &str) -> anyhow::Result<Vec<String>> {
+        let file_id = case.db.file_id(&case.src.join(relative_path));
+        Ok(lint_syntax(&case.db, file_id)?.as_slice().to_vec())
+    }
+
+    #[test]
+    fn abstract_instantiation_with_no_override_at_all() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "abc.py",
+            "
+                def abstractmethod(f): return f
+                class ABC: pass
+            ",
+        )?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                from abc import ABC, abstractmethod
+
+                class Base(ABC):
+                    @abstractmethod
+                    def f(self): ...
+
+                class Impl(Base):
+                    pass
+
+                Impl()
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors
+                .iter()
+                .any(|msg| msg.contains("unimplemented abstract method")),
+            "expected an unimplemented-abstract-method diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn in_operand_on_none_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                x = None
+                y = 1 in x
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("Unsupported operand")),
+            "expected an unsupported-operand diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn in_operand_on_list_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "y = 1 in [1, 2, 3]")?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "a list is iterable, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dataclass_order_without_eq_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "dataclasses.py",
+            "
+                def dataclass(*args, **kwargs): ...
+            ",
+        )?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                from dataclasses import dataclass
+
+                @dataclass(order=True, eq=False)
+                class C:
+                    x: int
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("order=True") && msg.contains("eq=False")),
+            "expected an order/eq conflict diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dataclass_order_with_default_eq_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "dataclasses.py",
+            "
+                def dataclass(*args, **kwargs): ...
+            ",
+        )?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                from dataclasses import dataclass
+
+                @dataclass(order=True)
+                class C:
+                    x: int
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "`eq` defaults to True, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn list_literal_element_with_wrong_type_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+                class D: pass
+
+                x: list[C] = [C(), D()]
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors
+                .iter()
+                .any(|msg| msg.contains("`D`") && msg.contains("expected `C`")),
+            "expected a literal-element-type-mismatch diagnostic naming `D`, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn list_literal_with_matching_elements_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+
+                x: list[C] = [C(), C()]
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "every element matches the annotation, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn del_on_never_bound_name_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                del y
+                y = 1
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("no reaching binding")),
+            "expected a del-never-bound diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn del_on_bound_name_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "y = 1\ndel y")?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "`y` is bound before the `del`, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cast_to_already_held_type_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+
+                x = C()
+                cast(C, x)
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("Redundant `cast`")),
+            "expected a redundant-cast diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cast_to_a_different_type_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+                class D: pass
+
+                x = C()
+                cast(D, x)
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "the cast target differs from the argument's type, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn return_in_finally_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                def f():
+                    try:
+                        pass
+                    finally:
+                        return 1
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("`return` inside a `finally`")),
+            "expected a return-in-finally diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn return_in_try_body_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                def f():
+                    try:
+                        return 1
+                    finally:
+                        pass
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "the `return` is in the `try` body, not `finally`, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assert_never_on_non_never_type_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+
+                x = C()
+                assert_never(x)
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("assert_never")),
+            "expected an assert_never diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assert_never_on_unresolved_call_result_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                def f():
+                    pass
+
+                x = f()
+                assert_never(x)
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().all(|msg| !msg.contains("assert_never")),
+            "x's type isn't precisely known (Unknown), should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assert_type_mismatch_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+                class D: pass
+
+                x = C()
+                assert_type(x, D)
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("Expected type")),
+            "expected an assert_type mismatch diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assert_type_match_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+
+                x = C()
+                assert_type(x, C)
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "the actual and expected types match, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn for_else_without_break_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                for x in [1, 2, 3]:
+                    print(x)
+                else:
+                    print('done')
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("redundant `else`")),
+            "expected a redundant-loop-else diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn for_else_with_break_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                for x in [1, 2, 3]:
+                    if x == 2:
+                        break
+                else:
+                    print('done')
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "the loop can exit via `break`, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn overriding_a_final_method_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "typing.py",
+            "
+                def final(f): return f
+            ",
+        )?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                from typing import final
+
+                class Base:
+                    @final
+                    def f(self): ...
+
+                class Sub(Base):
+                    def f(self): ...
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("@typing.final")),
+            "expected a final-override diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn overriding_a_non_final_method_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "typing.py",
+            "
+                def final(f): return f
+            ",
+        )?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class Base:
+                    def f(self): ...
+
+                class Sub(Base):
+                    def f(self): ...
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "base method is not `@typing.final`, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pop_on_empty_list_literal_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "x = [].pop()")?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("pop")),
+            "expected a pop-on-empty-literal diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pop_on_nonempty_list_literal_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "x = [1].pop()")?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "the list literal is not empty, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nan_equality_comparison_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "x = 1.0\ny = x == math.nan")?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("NaN")),
+            "expected a NaN-comparison diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ordinary_equality_comparison_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "x = 1.0\ny = x == 2.0")?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "neither operand is NaN, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn slots_conflicts_with_class_level_default_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C:
+                    __slots__ = ('x',)
+                    x = 0
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("`x`") && msg.contains("__slots__")),
+            "expected a slots-conflict diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn slots_without_matching_default_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C:
+                    __slots__ = ('x',)
+
+                    def __init__(self):
+                        self.x = 0
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "no class-level default shadows a slot, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dunder_all_entry_with_no_matching_name_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                def f(): pass
+
+                __all__ = ['f', 'missing']
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors
+                .iter()
+                .any(|msg| msg.contains("'missing'") && msg.contains("__all__")),
+            "expected a diagnostic for the unresolved `__all__` entry, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn dunder_all_entries_matching_module_names_are_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                def f(): pass
+
+                __all__ = ['f']
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "every `__all__` entry resolves, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn isinstance_on_already_narrowed_subject_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+
+                x = C()
+                isinstance(x, C)
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("Redundant `isinstance`")),
+            "expected a redundant-isinstance diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn isinstance_on_differently_typed_subject_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C: pass
+                class D: pass
+
+                x = C()
+                isinstance(x, D)
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            !errors.iter().any(|msg| msg.contains("Redundant `isinstance`")),
+            "subject is not narrowed to D, should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assert_on_nonempty_tuple_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "assert (x, 'message')")?;
+
+        let errors = lint_syntax_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("always true")),
+            "expected an always-true tuple-assert diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn assert_on_plain_condition_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "assert x, \"message\"")?;
+
+        let errors = lint_syntax_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "a plain assert should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn open_without_encoding_is_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "f = open('x.txt')")?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("encoding")),
+            "expected a missing-encoding diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn open_with_positional_encoding_is_not_flagged() -> anyhow::Result<()> {
+        let case = create_test()?;
+        write_to_path(&case, "a.py", "f = open('x.txt', 'r', -1, 'utf-8')")?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.is_empty(),
+            "a positional `encoding` argument should not be flagged, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn new_init_mismatch_not_flagged_through_new_vararg() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C:
+                    def __new__(cls, *args):
+                        return super().__new__(cls)
+
+                    def __init__(self, value):
+                        self.value = value
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            !errors.iter().any(|msg| msg.contains("__new__")),
+            "forwarding *args should not be flagged as a signature mismatch, got: {errors:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn new_init_mismatch_still_flagged_without_forwarding() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C:
+                    def __new__(cls):
+                        return super().__new__(cls)
+
+                    def __init__(self, value):
+                        self.value = value
+            ",
+        )?;
+
+        let errors = lint_errors(&case, "a.py")?;
+        assert!(
+            errors.iter().any(|msg| msg.contains("__new__")),
+            "expected a __new__/__init__ signature mismatch diagnostic, got: {errors:?}"
+        );
+        Ok(())
+    }
+}
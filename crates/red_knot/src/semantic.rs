@@ -21,7 +21,9 @@ use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 pub(crate) use symbol_table::{Dependency, SymbolId};
 use symbol_table::{ScopeId, ScopeKind, SymbolFlags, SymbolTable, SymbolTableBuilder};
-pub(crate) use types::{infer_definition_type, infer_symbol_public_type, Type, TypeStore};
+pub(crate) use types::{
+    infer_definition_type, infer_expr_type, infer_symbol_public_type, Type, TypeStore,
+};
 
 mod definitions;
 mod flow_graph;
@@ -303,7 +305,21 @@ impl SourceOrderVisitor<'_> for SemanticIndexer {
                 debug_assert!(self.current_definition.is_none());
                 self.current_definition =
                     Some(Definition::NamedExpr(TypedNodeKey::from_node(node)));
-                // TODO walrus in comprehensions is implicitly nonlocal
+                // TODO a comprehension (`ListComp`/`SetComp`/`DictComp`/`Generator`) doesn't get
+                // its own scope at all here -- unlike a function or class body, none of the four
+                // variants are specially handled in `visit_stmt`/`visit_expr`, so they fall
+                // through to the generic walk and everything inside (the `elt`/`key`/`value`
+                // expression, each `for` target, every `if` filter) is indexed directly into
+                // whatever scope contains the comprehension. That means a `for` target like `x`
+                // in `[x for x in items]` incorrectly leaks into the enclosing scope today (PEP
+                // 572 says only a walrus target should), while conversely a walrus inside a
+                // comprehension (`{(y := f(x)): y for x in items}`) binding `y` into the
+                // *enclosing* scope "already works" purely because there's no comprehension-local
+                // scope to wrongly bind it into in the first place. Properly supporting the walrus
+                // case without the `for`-target leak needs an actual comprehension scope pushed
+                // here (see `push_scope`/`ScopeKind`), with a `Named` target inside it specially
+                // walked to bind into the *parent* scope's symbol table instead of the
+                // comprehension's own, while every other binding in the comprehension stays local.
                 self.visit_expr(&node.target);
                 self.current_definition = None;
                 self.visit_expr(&node.value);
@@ -540,6 +556,88 @@ impl SourceOrderVisitor<'_> for SemanticIndexer {
                 // Onward, with current flow node set to our final Phi node.
                 self.set_current_flow_node(post_prior_clause);
             }
+            ast::Stmt::Try(node) => {
+                let before_try = self.current_flow_node();
+
+                // The body doesn't always run to completion -- any statement inside it may raise
+                // before a later one executes, so whatever it binds is only *possibly* bound by
+                // the time we reach a handler, rather than definitely bound the way a block that
+                // always completes would leave it. Approximate that with a phi joining the state
+                // after the body with the state from before it ever started (coarse: this treats
+                // a binding made by the body's first statement exactly as possibly-unbound as one
+                // made by its last, since flow nodes don't track "how far into a block" something
+                // happened).
+                let body_branch = self.flow_graph_builder.add_branch(before_try);
+                self.set_current_flow_node(body_branch);
+                self.visit_body(&node.body);
+                let after_body = self.current_flow_node();
+                let maybe_body_ran = self.flow_graph_builder.add_phi(after_body, before_try);
+
+                // No exception: the body ran to completion, so `else` (if any) sees the body's
+                // bindings as definite, not just possible.
+                self.set_current_flow_node(after_body);
+                self.visit_body(&node.orelse);
+                let mut post_try = self.current_flow_node();
+
+                // Handlers are mutually exclusive with each other and with the body completing
+                // normally, but we don't narrow which handler a given exception type reaches
+                // (the `isinstance` narrowing in `infer_constraint_type` applies to `if` tests
+                // only, not to an `except` clause), so each one is visited from the same "body
+                // partially ran" state and
+                // every outcome -- each handler's end state, plus the no-exception path above --
+                // is phi'd together.
+                for handler in &node.handlers {
+                    self.set_current_flow_node(maybe_body_ran);
+                    let ast::ExceptHandler::ExceptHandler(handler_node) = handler;
+                    if let Some(type_) = &handler_node.type_ {
+                        self.visit_expr(type_);
+                    }
+                    if let Some(name) = &handler_node.name {
+                        let def = Definition::ExceptHandler(TypedNodeKey::from_node(handler_node));
+                        self.add_or_update_symbol_with_def(name, def);
+                    }
+                    self.visit_body(&handler_node.body);
+                    if let Some(name) = &handler_node.name {
+                        // CPython implicitly does `del e` when the handler exits, whether
+                        // normally or via an exception -- rebind `e` to `Unbound` so reads after
+                        // the `try` see it as possibly-unbound rather than still typed as the
+                        // exception.
+                        self.add_or_update_symbol_with_def(name, Definition::Unbound);
+                    }
+                    post_try = self
+                        .flow_graph_builder
+                        .add_phi(self.current_flow_node(), post_try);
+                }
+
+                self.set_current_flow_node(post_try);
+                self.visit_body(&node.finalbody);
+            }
+            // `With` isn't specially indexed either, so we don't yet check that a context
+            // manager's `__enter__`/`__exit__` pair is well-typed, including whether `__exit__`'s
+            // return type is `bool`-like enough to statically suppress the exception it was
+            // given. That also means a `with` item's target (`as f`, or `as (a, b)` unpacking a
+            // tuple `__enter__` result) falls through to the generic walk below and is treated as
+            // a plain use rather than getting its own definition bound to `__enter__`'s return
+            // type -- so today, with multiple `with` items (`with open(a) as f, open(b) as g:`)
+            // or tuple-unpacking targets, neither `f`/`g` nor `a`/`b` are typed any more
+            // precisely than an unbound name.
+            //
+            // `For` is in the same boat: it isn't specially indexed, so the loop target falls
+            // through to the generic walk below as a plain use, with no definition recording the
+            // iterable's element type. This is true for both plain `for` and `async for` -- and
+            // `async for` has no special-casing to fall back on even if `for` did, since there's
+            // no iterable-protocol resolution (`__iter__`/`__next__`, or `__aiter__`/`__anext__`
+            // plus the await) anywhere in this crate yet to resolve an element type from.
+            //
+            // `Match` isn't indexed either, and unlike the above there's no existing
+            // `infer_*_definition`-style query for it to even attach a `todo_type!` to -- pattern
+            // captures (`case [a, b]:`, `case Point(x=px):`) fall through to the generic walk
+            // below and are bound with no type at all. Giving them a real one requires
+            // structurally matching the subject's type against the pattern (sequence/mapping
+            // unpacking for `MatchSequence`/`MatchMapping`, attribute lookup for `MatchClass`'s
+            // keyword patterns), in the same spirit as the tuple-unpacking distribution an
+            // ordinary `Assignment` still needs (see the TODO in `infer.rs`) -- neither exists
+            // yet.
             _ => {
                 ast::visitor::source_order::walk_stmt(self, stmt);
             }
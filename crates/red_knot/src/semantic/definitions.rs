@@ -20,9 +20,45 @@ pub enum Definition {
     Assignment(TypedNodeKey<ast::StmtAssign>),
     AnnotatedAssignment(TypedNodeKey<ast::StmtAnnAssign>),
     NamedExpr(TypedNodeKey<ast::ExprNamed>),
+    /// `except ... as e:` binds `e` to (the union of) the handler's exception type(s); see the
+    /// `Definition::Unbound` rebinding inserted after the handler body in `SemanticIndexer` for
+    /// how the implicit CPython `del e` on handler exit is modeled.
+    ExceptHandler(TypedNodeKey<ast::ExceptHandlerExceptHandler>),
     /// represents the implicit initial definition of every name as "unbound"
     Unbound,
-    // TODO with statements, except handlers, function args...
+    // TODO with statements, function args, for-loop targets (including
+    // multi-target unpacking like `for i, x in enumerate(xs):`, and `async for` -- which needs
+    // its element type resolved through `__aiter__`/`__anext__` plus an await rather than the
+    // synchronous `__iter__`/`__next__` an ordinary `for` would use), and augmented assignment
+    // (`x |= y`, `x += y`, ...) -- `StmtAugAssign` isn't visited anywhere in `SemanticIndexer`, so
+    // `x` after `x |= y` isn't rebound to anything; reads of `x` past that point still see
+    // whatever definition preceded it. This blocks typing `dict[K, V1] | dict[K, V2]`-style merges
+    // through `|=` specifically (the corresponding `BinOp` path for plain `d1 | d2` has the same
+    // problem one level down: dict/set/list instances don't carry element/value type arguments at
+    // all, and `resolve_bin_op` has no dunder-dispatch for `Type::Instance` operands in the first
+    // place -- see the TODOs on both).
+    //
+    // A function parameter definition in particular is the missing piece for a lint flagging
+    // reassignment of a parameter to a type incompatible with its annotation (e.g. `def f(x:
+    // int): x = "s"`): without a `Definition` for the parameter itself, there's no declared type
+    // to check a later `Assignment` against, only the annotation on the `FunctionDef` node, which
+    // we don't currently thread through to the body's bindings at all.
+    //
+    // The missing multi-target unpacking definition is also why `for k, v in obj.items():` can't
+    // be shape-checked against what `obj.items()` actually yields: there's nowhere to attach a
+    // "this target expects a 2-tuple" expectation derived from the `for` target's own shape, and
+    // even if there were, `obj.items()`'s element type is `Unknown` regardless of `obj`'s type --
+    // dict/set/list instances don't carry element/value type arguments yet (see the literal-type
+    // TODO in `infer.rs`), so there's no way to tell a real mapping's `.items()` apart from some
+    // unrelated `.items()` method that happens to return a non-tuple iterator.
+    //
+    // `del x` isn't visited specially either (`StmtDelete` falls through to the generic walk the
+    // same as every other unindexed statement above), so it doesn't re-bind `x` to `Unbound`
+    // for reads after the `del`, and no use-after-`del` diagnostic exists. Whether `x` had a
+    // reaching binding to delete in the first place is checked by `lint_del_never_bound` in
+    // `lint.rs`, from the inferred type of the deleted name itself (`Unbound`, or a union
+    // containing it).
+
 }
 
 #[derive(Clone, Debug)]
@@ -13,7 +13,7 @@ use rustc_hash::FxHashMap;
 
 pub(crate) mod infer;
 
-pub(crate) use infer::{infer_definition_type, infer_symbol_public_type};
+pub(crate) use infer::{infer_definition_type, infer_expr_type, infer_symbol_public_type};
 use red_knot_module_resolver::ModuleName;
 use ruff_python_ast::name::Name;
 
@@ -42,7 +42,62 @@ pub enum Type {
     Union(UnionTypeId),
     Intersection(IntersectionTypeId),
     IntLiteral(i64),
-    // TODO protocols, callable types, overloads, generics, type vars
+    // TODO protocols, callable types, overloads, generics, type vars. A `TypeVar` variant
+    // carrying its bound/constraints is also a prerequisite for checking a bounded-typevar
+    // parameter's default against that bound (e.g. `def f[T: int](x: T = 0)`) -- that check
+    // additionally needs parameter definitions to exist at all (see the `Definition` TODO).
+    // Calling a generic function also needs to *solve* each typevar from the call's argument
+    // types and then check the solved type against that typevar's bound/constraints (e.g.
+    // `def f[T: (int, str)](x: T)` called with a `float` should fail since `float` satisfies
+    // neither member of the constraint set) -- there's no call inference to do that solving in
+    // the first place yet.
+    //
+    // A `TypeVar` variant is also a prerequisite for flagging a `TypeVar` used in an annotation
+    // outside of any enclosing generic function/class's type parameters (e.g. a module-level
+    // `def f(x: T) -> T` where `T` was never bound by `def f[T](...)`): checking that needs both
+    // a way to recognize an annotation expression as referring to a typevar at all, and a
+    // `GenericContext` (or similar) recording which typevars are in scope at each generic
+    // function/class so a reference can be checked against it. Neither exists yet.
+    //
+    // A `ParamSpec` is the same shape of gap one level up: `Callable[P, R]` and a
+    // `functools.wraps`-preserving decorator (`def wrapper(f: Callable[P, R]) -> Callable[P, R]`)
+    // both need a distinct type kind for `P` that can stand in for an entire parameter list
+    // (carrying `P.args`/`P.kwargs` as its own pseudo-attributes) rather than a single type, plus
+    // a `Callable[...]` representation to hold it in. Neither exists yet; a bare `Callable[...]`
+    // annotation isn't resolved to anything today (there's no `infer_type_expression`-equivalent
+    // query at all, see the `Subscript` TODO in `infer.rs`), so there's nowhere to plug a
+    // `ParamSpec` in even once it exists.
+    //
+    // Also no `TypedDict` variant (or any other structural/field-based class kind -- a
+    // `NamedTuple` has the same shape of gap). A `class Movie(TypedDict): ...` is just an
+    // ordinary `Type::Class` today, with its `TypedDict` base resolved the same as any other
+    // base and no special field-merging: there's no per-field required-ness to track, and
+    // `ClassTypeId`'s MRO walk (used by `get_class_member`/`get_super_class_member`) resolves a
+    // name to one winning definition rather than merging a parent's and a child's fields
+    // together the way a `TypedDict` needs (a child field should override a parent field of the
+    // same name rather than just shadow it in lookup order, and a mismatched override -- e.g.
+    // narrowing `int` to `bool` -- should be flagged, which needs a type-compatibility check this
+    // enum doesn't have yet either).
+    //
+    // Checking a `TypedDict`'s construction call (`Movie(name="x")` missing a required `year`,
+    // or passing an unknown `genre` key) is downstream of the same gap: there's no per-field
+    // required-ness (from `total=False` or a per-field `NotRequired[...]`/`Required[...]`
+    // qualifier) to check the call's keyword arguments against, and no call-argument-to-field
+    // matching at all, since regular calls don't validate keyword arguments against anything
+    // today either (see the TODO on the `Call` arm in `infer.rs` -- a `TypedDict`'s
+    // synthesized constructor would need to be recognized as a special callable shape in the
+    // same place).
+    //
+    // `typing.NamedTuple` has no synthesized-member support either, for the same underlying
+    // reason: there's no mechanism for a class to gain members that don't come from its literal
+    // body at all (`ClassType`'s members are exactly what's written in the class body, see the
+    // `@dataclass` TODO on synthesized `__init__` above `literal_bool` in `lint.rs`). A
+    // `NamedTuple` subclass's `_make(iterable)` (returning
+    // the subclass's own instance type), `_asdict()` (returning `dict[str, <union of field
+    // types>]`, or more precisely a `TypedDict`-shaped mapping once that exists), and `_fields`
+    // (a tuple of the field names as `str` literals, derived from the class's own annotated
+    // field list) would all need to be synthesized members attached somewhere other than the
+    // class body's own bindings.
 }
 
 impl Type {
@@ -68,9 +123,43 @@ impl Type {
             Type::Function(_) => todo!("attribute lookup on Function type"),
             Type::Module(module_id) => module_id.get_member(db, name),
             Type::Class(class_id) => class_id.get_class_member(db, name),
-            Type::Instance(_) => {
-                // TODO MRO? get_own_instance_member, get_instance_member
-                todo!("attribute lookup on Instance type")
+            Type::Instance(class_id) => {
+                // TODO this only looks at class members (via the MRO), not instance attributes
+                // assigned in `__init__` and friends, and it doesn't run the descriptor protocol
+                // (`__get__`) on what it finds, so e.g. a `@property` or an overloaded descriptor
+                // resolves to the descriptor object itself rather than the value it would produce
+                // for instance access. This also means `@dataclass`-generated classes get no
+                // special treatment: a computed attribute backed by `@property` on a dataclass
+                // resolves to the property object rather than its getter's return type, same as
+                // on any other class, since we don't recognize `@dataclass` or synthesize fields
+                // from it in the first place.
+                //
+                // There's also no `validate_attribute_assignment`-equivalent at all, i.e. nothing
+                // checks an assignment target's type against a `@property`'s setter parameter
+                // type -- attribute assignment isn't validated against any declared type, let
+                // alone one resolved from the *inherited* setter when a subclass overrides only
+                // the getter (which would need walking the MRO past the overriding class to find
+                // the nearest setter, rather than stopping at the first class that defines the
+                // name at all, the way `get_class_member` does for reads).
+                //
+                // A `@property` with no setter at all deserves its own clear diagnostic
+                // ("Property `x` on type `Foo` has no setter") rather than falling out of generic
+                // assignment validation as some confusing `Never`/`NoReturn`-flavored message --
+                // but there's neither a dedicated `PropertyInstance` type to test for (a
+                // `@property` is just the opaque descriptor object `get_member` above returns
+                // unevaluated) nor any attribute-assignment validation to hang that diagnostic off
+                // of in the first place.
+                //
+                // Once a property's getter return type *is* resolved here, narrowing it (`if
+                // obj.maybe_prop is not None:`) needs care a plain name read doesn't: narrowing is
+                // keyed by `SymbolId` via the flow graph (see the `Attribute` arm's own narrowing
+                // TODO in `infer.rs`), which has no notion of "this is a property access, and
+                // repeating it might call the getter again and observe a different value" -- a
+                // property should conservatively narrow only a single captured access (`v :=
+                // obj.prop`, or a plain local `v = obj.prop`), never two syntactically-identical
+                // `obj.prop` reads treated as if they always produce the same value the way two
+                // reads of a plain name would.
+                class_id.get_class_member(db, name)
             }
             Type::Union(union_id) => {
                 let jar: &SemanticJar = db.jar()?;
@@ -138,7 +227,16 @@ impl Type {
                     _ => todo!("complete binop right_ty support for IntLiteral"),
                 }
             }
-            _ => todo!("complete binop support"),
+            // TODO every other left-hand type (including `Type::Instance`) falls back to
+            // `Unknown` here rather than resolving the right dunder (`__add__`/`__radd__`/etc.)
+            // on the class, since there's no call inference to invoke that method with at all.
+            // This is exactly what blocks `IntEnum`/`IntFlag`/`StrEnum` member arithmetic (e.g.
+            // `Color.RED + 1` where `Color(IntEnum)`): those members would need recognizing that
+            // their class's MRO includes `int`/`str` (itself unimplemented -- nothing inspects
+            // `ClassType::bases` to answer "is this a subtype of `int`"), and then either
+            // widening to `int`/`str` for the operation or dispatching to the real dunder, not
+            // just binop support for `Type::IntLiteral`.
+            _ => Ok(Type::Unknown),
         }
     }
 }
@@ -569,11 +667,69 @@ impl ClassTypeId {
 
     /// Get own class member or fall back to super-class member.
     fn get_class_member(self, db: &dyn SemanticDb, name: &Name) -> QueryResult<Option<Type>> {
-        self.get_own_class_member(db, name)
-            .or_else(|_| self.get_super_class_member(db, name))
+        // `get_own_class_member` returning `Ok(None)` means "not found on this class", not an
+        // error, so the super-class fallback has to be driven by that `None` rather than by
+        // `.or_else`, which only ever runs on `Err`.
+        if let Some(own_member) = self.get_own_class_member(db, name)? {
+            return Ok(Some(own_member));
+        }
+        self.get_super_class_member(db, name)
     }
 
     // TODO: get_own_instance_member, get_instance_member
+
+    /// Names of methods decorated `@abstractmethod` anywhere in this class's MRO that aren't
+    /// overridden by a concrete (non-abstract) implementation closer to this class -- i.e. the
+    /// methods that still need implementing before this class can be instantiated.
+    pub(crate) fn unimplemented_abstract_methods(
+        self,
+        db: &dyn SemanticDb,
+        abstractmethod: GlobalSymbolId,
+    ) -> QueryResult<Vec<Name>> {
+        let mut abstract_names = Vec::new();
+        self.collect_abstract_method_names(db, abstractmethod, &mut abstract_names)?;
+        let mut unimplemented = Vec::new();
+        for name in abstract_names {
+            if let Some(Type::Function(func)) = self.get_class_member(db, &name)? {
+                if func.has_decorator(db, abstractmethod)? {
+                    unimplemented.push(name);
+                }
+            }
+        }
+        Ok(unimplemented)
+    }
+
+    fn collect_abstract_method_names(
+        self,
+        db: &dyn SemanticDb,
+        abstractmethod: GlobalSymbolId,
+        names: &mut Vec<Name>,
+    ) -> QueryResult<()> {
+        let class = self.class(db)?;
+        let index = semantic_index(db, self.file_id)?;
+        for symbol_id in index.symbol_table().symbol_ids_for_scope(class.scope_id) {
+            let name = symbol_id.symbol(index.symbol_table()).name();
+            if let Type::Function(func) = infer_symbol_public_type(
+                db,
+                GlobalSymbolId {
+                    file_id: self.file_id,
+                    symbol_id,
+                },
+            )? {
+                if func.has_decorator(db, abstractmethod)?
+                    && !names.iter().any(|n| n.as_str() == name)
+                {
+                    names.push(Name::new(name));
+                }
+            }
+        }
+        for base in class.bases() {
+            if let Type::Class(base) = base {
+                base.collect_abstract_method_names(db, abstractmethod, names)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -768,6 +924,16 @@ pub(crate) struct ClassType {
     scope_id: ScopeId,
     /// Types of all class bases
     bases: Vec<Type>,
+    // TODO a `class C(Base, metaclass=Meta, **kwargs)`'s keyword arguments (metaclass included)
+    // aren't recorded here at all, only the positional bases. Without them we can't check
+    // `kwargs` against the metaclass's `__new__`/`__init_subclass__` parameters -- that check
+    // additionally needs the call-site/parameter-list infra noted on `FunctionType` above. The
+    // metaclass specifically not being recorded also means instantiating a class whose metaclass
+    // defines a non-default `__call__` (singleton/factory metaclasses) can't route through that
+    // `__call__`'s return type the way real instantiation would: calling a class by name (see
+    // the `Call` arm in `infer.rs`) always infers as a plain instance of that class, without
+    // consulting any metaclass, and calls other than the few that arm special-cases infer as
+    // `Unknown`.
 }
 
 impl ClassType {
@@ -789,7 +955,78 @@ pub(crate) struct FunctionType {
     /// scope of this function's body
     scope_id: ScopeId,
     /// types of all decorators on this function
+    //
+    // TODO decorators are only stored as opaque `Type`s, never interpreted. There's no
+    // recognition of specific decorator patterns like `@functools.singledispatchmethod` plus its
+    // `@method.register` implementations (whose return type should be the union of the
+    // registered implementations' return types) -- doing that needs call inference on the
+    // registrations and a way to associate them back with the decorated method.
     decorators: Vec<Type>,
+    // TODO record the parameter list (names, kinds, annotations, defaults) here. Without it we
+    // can't validate call sites at all: matching positional/keyword arguments against parameters
+    // (e.g. flagging a keyword argument that duplicates one already filled positionally), keyword
+    // argument names, arity, or argument types -- including a starred argument (`f(*items)`)
+    // splatted against fixed positional parameters, which needs mapping each of a fixed-length
+    // tuple's element types onto the corresponding parameter slot (there's no such
+    // argument-to-parameter "`Bindings`" matching of any kind here, starred or not) and, for a
+    // non-fixed-length `items`, falling back to checking its single element type against every
+    // remaining parameter. That arity check is also what a bare-exception
+    // `raise E`/`raise E from Y` needs to flag `E`/`Y` being a class that requires constructor
+    // arguments it wasn't given -- today neither `Raise` statements nor a notion of "can this
+    // class be constructed with no arguments" exist anywhere in this checker. It's also what
+    // `super().__init__(...)` would need checked against: there's no `super()` resolution at all
+    // (no `Type::BoundSuper` or equivalent -- a bare call to the builtin `super` just falls into
+    // the same `Unknown` that every call of something other than a resolved class or a
+    // special-cased function gets), so even finding the next-in-MRO
+    // `__init__` to check against isn't possible yet, let alone validating the call's arguments
+    // against it once found.
+    //
+    // A per-overload `Signature` (built from that same parameter list) is also the missing piece
+    // for any `@overload` checking, e.g. flagging a later overload whose parameter types are a
+    // strict subset of an earlier one's (making it unreachable), or resolving which overload of
+    // an overloaded `__init__` a given set of constructor arguments matches: we don't recognize
+    // the `@typing.overload` decorator at all, let alone group same-named overloads together or
+    // compare their signatures pairwise. That includes an overloaded dunder like `__add__`: the
+    // binary-operator resolution in `Type::resolve_bin_op` has no overload set to pick from, so
+    // it can only ever see one `__add__` definition, not select among several based on the right
+    // operand's type.
+    //
+    // We also don't record whether the function is `async` or infer a return type at all, so
+    // there's no way yet to distinguish an `async def` (whose call result is a `Coroutine[...]`
+    // that must be awaited) from a sync function when checking it against a `Callable[[], T]`
+    // parameter -- the bug this would catch (passing a coroutine where the awaited value, not
+    // the coroutine itself, is expected) needs both pieces.
+    //
+    // Inferring a return type at all would also need to collect every `return`'s value type
+    // across the body (and union them), the way branch merging already happens for a plain
+    // assignment's narrowed type. An opt-in lint flagging a "surprising" union return type (e.g.
+    // `int` in one branch, `str` in another) would build directly on that collection, once it
+    // exists.
+    //
+    // That same `return`-value collection is also the missing piece for checking a generator
+    // function's body against an explicit `Generator[Y, S, R]` return annotation: verifying each
+    // `yield`'s value is assignable to `Y`, each `.send()`-received type against `S` (itself
+    // blocked on `yield` even having an inferred type at all -- see the TODO on the
+    // `Yield`/`YieldFrom` arm in `infer.rs`), and each `return`'s value against `R`. Even with
+    // that collection in hand, there's nowhere to read `Y`/`S`/`R` back out of the annotation in
+    // the first place: `Type::Instance` only carries a bare `ClassTypeId`, with no type arguments
+    // recorded for `Generator` or any other generic class.
+    //
+    // `lint_bad_overrides` in `lint.rs` only checks that an `@override` method's name exists
+    // somewhere in the base class's MRO, never that the override's own signature is a valid
+    // subtype of the overridden one (contravariant parameter types, covariant return type --
+    // narrowing a parameter or widening a return is the classic Liskov violation). That check
+    // needs exactly the parameter list and inferred/declared return type this struct doesn't
+    // have, plus an `is_assignable_to`-style relation between two `Type`s to compare them with,
+    // neither of which exists yet either.
+    //
+    // The same missing parameter list also blocks recognizing a PEP 646 variadic positional
+    // parameter (`def f(*args: *Ts)` or the older `*args: *tuple[int, *Ts]` spelling): even once
+    // individual parameters exist, a starred type annotation needs its own representation
+    // distinct from an ordinary annotation (it unpacks a tuple type, fixed- or variable-length,
+    // across the rest of the positional parameter slots rather than annotating `args` itself with
+    // that type), and that in turn depends on tuple types carrying per-element type arguments at
+    // all, which they don't (see the literal-type TODO in `infer.rs`).
 }
 
 impl FunctionType {
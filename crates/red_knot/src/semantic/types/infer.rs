@@ -2,6 +2,7 @@
 
 use red_knot_module_resolver::ModuleName;
 use ruff_python_ast as ast;
+use ruff_python_ast::name::Name;
 use ruff_python_ast::AstNode;
 use std::fmt::Debug;
 
@@ -193,6 +194,21 @@ pub fn infer_definition_type(
                     .resolve(ast.as_any_node_ref())
                     .expect("node key should resolve");
 
+                // TODO `node.parameters` is never looked at here (see the `FunctionType` TODO),
+                // so an opt-in "strict mode" lint flagging unannotated parameters (whose declared
+                // type would then fall back to `Unknown`) has nothing to inspect yet either.
+                //
+                // Because parameters aren't looked at, `self`/`cls` aren't given any type at all:
+                // a bare `Name` use only resolves against the module's root scope (see the TODO
+                // on `Expr::Name` in `infer_expr_type`), so `self` inside a method infers as
+                // `Unknown`, not even as the class's unparameterized instance type. For a generic
+                // class (`class Box(Generic[T])`),
+                // `self` should instead be `Box[T]` using the class's own type variables, so that
+                // e.g. `self.items` inside a method reads as `list[T]` rather than `list[Unknown]`
+                // -- but there's no `GenericContext` (or any representation of a class's type
+                // parameters at all) to read `T` from, no per-parameter `Definition` to hang a
+                // `self`-specific type on, and `Type::Instance`'s bare `ClassTypeId` has nowhere
+                // to put `T` once bound. All three would need to exist before this is possible.
                 let decorator_tys = node
                     .decorator_list
                     .iter()
@@ -214,14 +230,35 @@ pub fn infer_definition_type(
             let parsed = parse(db.upcast(), file_id)?;
             let ast = parsed.syntax();
             let node = node_key.resolve_unwrap(ast.as_any_node_ref());
-            // TODO handle unpacking assignment
+            // TODO handle unpacking assignment: distribute a fixed-size tuple RHS's element
+            // types across a tuple target's elements (`a, b = b, a`, `x, y = func()`) instead of
+            // inferring the whole RHS as one type. Once that exists, a target with its own
+            // declared type (e.g. `x: int`) should be checked against its specific distributed
+            // element, so a mismatch is reported against that element/target pair rather than
+            // the tuple as a whole.
             infer_expr_type(db, file_id, &node.value)
         }
         Definition::AnnotatedAssignment(node_key) => {
             let parsed = parse(db.upcast(), file_id)?;
             let ast = parsed.syntax();
             let node = node_key.resolve_unwrap(ast.as_any_node_ref());
-            // TODO actually look at the annotation
+            // TODO actually look at the annotation. In particular we don't strip (or even
+            // recognize) a `typing.ClassVar[...]` qualifier here, so a `ClassVar[int]`-annotated
+            // attribute's declared type is never derived from the annotation at all -- we infer
+            // purely from the RHS value below, same as a plain unannotated assignment. This
+            // matters for both read paths (a `ClassVar` should read as its inner type from either
+            // the class or an instance) and, separately, for flagging assignment to a `ClassVar`
+            // through an instance.
+            //
+            // The same missing piece is why a class-body attribute's default can't be checked
+            // against its own annotation (`class C: x: int = "s"` should flag `"s"` against
+            // `int`, `ClassVar[int] = "s"` included): this function only ever derives a type from
+            // the RHS `value` below and never evaluates `node.annotation` into a `Type` to compare
+            // it against at all, for a class-body attribute or anywhere else. Doing that needs an
+            // `infer_type_expression`-equivalent (evaluating an expression as a type annotation,
+            // as opposed to inferring a runtime expression's value type the way `infer_expr_type`
+            // does) plus an `is_assignable_to`-style compatibility check between the two `Type`s,
+            // neither of which exists anywhere in this crate yet.
             let Some(value) = &node.value else {
                 return Ok(Type::Unknown);
             };
@@ -234,6 +271,36 @@ pub fn infer_definition_type(
             let node = node_key.resolve_unwrap(ast.as_any_node_ref());
             infer_expr_type(db, file_id, &node.value)
         }
+        Definition::ExceptHandler(node_key) => {
+            let parsed = parse(db.upcast(), file_id)?;
+            let ast = parsed.syntax();
+            let node = node_key.resolve_unwrap(ast.as_any_node_ref());
+            // `except Foo as e` / `except (Foo, Bar) as e` binds `e` to an instance of the
+            // named exception type(s), not to the class itself -- a bare `except:` has no
+            // `type_` at all, but that can only coexist with `as e` in invalid syntax our parser
+            // wouldn't have accepted, so this is unreachable rather than a real `Unknown` case.
+            let class_exprs: Vec<&ast::Expr> = match node.type_.as_deref() {
+                Some(ast::Expr::Tuple(ast::ExprTuple { elts, .. })) => elts.iter().collect(),
+                Some(other) => vec![other],
+                None => vec![],
+            };
+            let mut tys = Vec::with_capacity(class_exprs.len());
+            for class_expr in class_exprs {
+                tys.push(match infer_expr_type(db, file_id, class_expr)? {
+                    Type::Class(class_id) => Type::Instance(class_id),
+                    // TODO an exception type that doesn't resolve to a plain in-module class
+                    // reference (e.g. a builtin like `ValueError`, which isn't resolved at all)
+                    // falls back to `Unknown` rather than `Instance`, same limitation as
+                    // `isinstance`'s narrowing below.
+                    _ => Type::Unknown,
+                });
+            }
+            match tys.len() {
+                0 => Ok(Type::Unknown),
+                1 => Ok(tys.remove(0)),
+                _ => Ok(type_store.add_union(file_id, &tys)),
+            }
+        }
     }
 }
 
@@ -254,7 +321,17 @@ fn infer_constraint_type(
     let symbol_name = symbol_id.symbol_id.symbol(&index.symbol_table).name();
     // TODO narrowing attributes
     // TODO narrowing dict keys
-    // TODO isinstance, ==/!=, type(...), literals, bools...
+    // TODO ==/!=, type(...), literals, bools...
+    //
+    // Discriminated-union narrowing (`x: A | B` where `A`/`B` carry a distinguishing literal
+    // attribute, narrowed by `if x.kind == "a":`) needs both of the above at once: `left` would
+    // be an `ast::Expr::Attribute` rather than a bare `Name`, which the match below doesn't
+    // handle at all (attribute narrowing isn't implemented -- the flow graph this function reads
+    // from is keyed only by `SymbolId`, so there's nowhere to record a constraint on `x.kind`
+    // specifically), and the comparator would need to be recognized as a string literal to match
+    // against each union member's discriminator value, which also isn't possible since `Type`
+    // has no string-literal variant (only `IntLiteral`) -- string literals just infer as
+    // `Unknown` today.
     match expression {
         ast::AnyNodeRef::ExprCompare(ast::ExprCompare {
             left,
@@ -283,12 +360,40 @@ fn infer_constraint_type(
                 _ => Ok(None),
             }
         }
+        // `isinstance(symbol, SomeClass)` narrows `symbol` to an instance of `SomeClass`.
+        // TODO narrow to a union when the second argument is a tuple of classes
+        // TODO narrow the `else` branch to the negation (an intersection with `~SomeClass`)
+        // TODO this only narrows to `SomeClass`'s MRO, so `isinstance(x, MyAbc)` where `x`'s
+        // static type was separately passed to `MyAbc.register(...)` as a virtual subclass (not
+        // tracked anywhere in this module) is treated the same as any other unrelated class --
+        // the narrowing here doesn't fail loudly, but it also can't narrow `x` to anything
+        // `MyAbc`-related, since structurally it isn't.
+        ast::AnyNodeRef::ExprCall(ast::ExprCall {
+            func, arguments, ..
+        }) => {
+            let ast::Expr::Name(ast::ExprName { id: func_name, .. }) = func.as_ref() else {
+                return Ok(None);
+            };
+            if func_name != "isinstance" || arguments.args.len() != 2 {
+                return Ok(None);
+            }
+            let ast::Expr::Name(ast::ExprName { id, .. }) = &arguments.args[0] else {
+                return Ok(None);
+            };
+            if id != symbol_name {
+                return Ok(None);
+            }
+            match infer_expr_type(db, file_id, &arguments.args[1])? {
+                Type::Class(class_id) => Ok(Some(Type::Instance(class_id))),
+                _ => Ok(None),
+            }
+        }
         _ => Ok(None),
     }
 }
 
 /// Infer type of the given expression.
-fn infer_expr_type(db: &dyn SemanticDb, file_id: FileId, expr: &ast::Expr) -> QueryResult<Type> {
+pub fn infer_expr_type(db: &dyn SemanticDb, file_id: FileId, expr: &ast::Expr) -> QueryResult<Type> {
     // TODO cache the resolution of the type on the node
     let index = semantic_index(db, file_id)?;
     match expr {
@@ -316,8 +421,25 @@ fn infer_expr_type(db: &dyn SemanticDb, file_id: FileId, expr: &ast::Expr) -> Qu
             }
         }
         ast::Expr::Attribute(ast::ExprAttribute { value, attr, .. }) => {
+            // TODO this always resolves through the member lookup on `value`'s type, so a read of
+            // `self.x` right after `self.x = some_int` doesn't see the narrower `int` the way a
+            // plain name read would (`FlowGraph`/`reachable_definitions` below are keyed by
+            // `SymbolId`, one per name, with no equivalent "place" for an attribute expression).
+            // Narrowing attribute places the same way would need the flow graph to track
+            // something like `PlaceExpr` (value's symbol + attribute name) rather than only
+            // `SymbolId`.
             let value_type = infer_expr_type(db, file_id, value)?;
             let attr_name = &attr.id;
+            // TODO `get_member` returning `None` (the attribute doesn't exist anywhere in
+            // `value_type`'s MRO) is silently mapped to `Unknown` here with no diagnostic pushed
+            // at all -- there's no "unresolved attribute" lint anywhere in `lint.rs` yet, unlike
+            // `lint_unresolved_imports`'s equivalent for module names. Adding one (and, on top of
+            // it, a "did you mean" suggestion computed by edit distance against every name
+            // `get_member` *would* have accepted, limited to distance 2) needs this call site to
+            // surface the `None` case to a `SemanticLintContext` rather than papering over it here
+            // in type inference, plus some way to enumerate a class's full member set (today
+            // `get_member`/`get_class_member` only ever check one name at a time, there's no
+            // "list all names visible via this class's MRO" query to feed candidates from).
             value_type
                 .get_member(db, attr_name)
                 .map(|ty| ty.unwrap_or(Type::Unknown))
@@ -338,10 +460,288 @@ fn infer_expr_type(db: &dyn SemanticDb, file_id: FileId, expr: &ast::Expr) -> Qu
             let jar: &SemanticJar = db.jar()?;
             Ok(jar.type_store.add_union(file_id, &[body_ty, else_ty]))
         }
+        // TODO infer `builtins.list`/`set`/`dict`/`tuple` specialized with the element type(s)
+        // (e.g. `[1, 2, 3]` as `list[int]`, joining each element's type with `add_union` the same
+        // way `Expr::If` above joins its two branches; an empty literal stays unspecialized or
+        // falls back to `Unknown` for its element type), and when a literal is the RHS of an
+        // annotated assignment, check each element against the target's element type
+        // individually so we can point at the offending element instead of only the literal as a
+        // whole. Both need `Type` to be able to represent a specialized generic instance at all
+        // (a class plus its type arguments), which doesn't exist yet -- `Type::Instance` only
+        // carries a bare `ClassTypeId`. A starred element (`[*a, *b]`, `(*a, x)`) compounds this:
+        // even once plain elements are unioned into the overall element type, a starred one needs
+        // its *own* element type pulled out of `a`/`b` via the iterable protocol first (the same
+        // `__iter__`/`__next__` resolution the `for`-loop and `yield from` TODOs elsewhere already
+        // need and don't have) rather than treating the whole starred expression as one element,
+        // and for a tuple display specifically, a starred operand with a statically-known fixed
+        // arity (`a: tuple[int, str]`) should splice each of its element types in at that position
+        // rather than collapsing them into the same single unioned element type a `list`/`set`
+        // display would use.
+        ast::Expr::List(_) | ast::Expr::Set(_) | ast::Expr::Dict(_) | ast::Expr::Tuple(_) => {
+            Ok(Type::Unknown)
+        }
+        // TODO model callable types and general return-type inference: there's no `Type`
+        // variant for "a callable" at all, so a user-defined function's own return type can't be
+        // looked up from a call site, and no stdlib call gets anything but `Unknown` unless it's
+        // special-cased below by `called_function_name`. `called_function_name` lets a handful
+        // of well-known functions be recognized by their bare or qualified name without that
+        // infrastructure; each arm below documents the further gap that keeps the rest of that
+        // function's siblings (`sorted`, `str.encode`, `re.match`, etc.) from being handled the
+        // same way.
+        ast::Expr::Call(call) => {
+            let called_name = called_function_name(call);
+
+            // Calling a class object by name produces an instance of that class.
+            if let ast::Expr::Name(_) = call.func.as_ref() {
+                if let Type::Class(class_id) = infer_expr_type(db, file_id, &call.func)? {
+                    return Ok(Type::Instance(class_id));
+                }
+            }
+
+            // `typing.cast(T, x)` evaluates to `T` itself, not whatever `x`'s inferred type
+            // happens to be -- mirrors the same argument-shape check `RedundantCastVisitor` in
+            // `lint.rs` uses to find the call in the first place. Only a bare class reference is
+            // handled as `T`; a qualified target like `cast(Final[int], x)` or a string forward
+            // reference like `cast("list[int]", x)` both fall through to `Unknown` below, since
+            // there's neither a qualifier type to detect `Final[...]`/`ClassVar[...]` with, nor
+            // an `infer_type_expression`-equivalent that can evaluate a type annotation (as
+            // opposed to a runtime value) out of a string or a subscript expression.
+            if called_name == Some("cast") && call.arguments.args.len() == 2 {
+                if let Type::Class(target_class) =
+                    infer_expr_type(db, file_id, &call.arguments.args[0])?
+                {
+                    return Ok(Type::Instance(target_class));
+                }
+            }
+
+            // `min(a, b, ...)`/`max(a, b, ...)` called with two or more positional candidates
+            // (as opposed to a single iterable argument, e.g. `min(xs)`, which needs the
+            // iterable-element extraction the literal-display TODO above still lacks) return the
+            // union of each candidate's own type, since the result is always one of them. The
+            // `key=`/`default=` keyword forms are left unhandled -- `default` changes the result
+            // type too (unioned in alongside the candidates) but distinguishing "called with one
+            // iterable and a default" from "called with one candidate and a default" needs the
+            // iterable-element extraction this arm is specifically avoiding. `sorted` isn't
+            // handled here at all: unlike `min`/`max`, its result type is the *iterable's*
+            // element type (as a `list`), not a union of its own positional arguments, so it
+            // needs that same iterable-element extraction before anything can be said about it.
+            if matches!(called_name, Some("min") | Some("max"))
+                && call.arguments.args.len() >= 2
+                && call.arguments.keywords.is_empty()
+            {
+                let mut arg_types = Vec::with_capacity(call.arguments.args.len());
+                for arg in &call.arguments.args {
+                    arg_types.push(infer_expr_type(db, file_id, arg)?);
+                }
+                let jar: &SemanticJar = db.jar()?;
+                return Ok(jar.type_store.add_union(file_id, &arg_types));
+            }
+
+            // `sum(iterable, start)` returns `start`'s own type when `start` is given explicitly
+            // (its presence is what determines the result type at all -- `sum([1], "")` is a
+            // `TypeError` at runtime, not a `str`). The far more common no-`start` form,
+            // `sum(iterable)`, still falls through to `Unknown` below: its result type comes from
+            // the iterable's element type, which needs the same iterable-element extraction the
+            // `min`/`max` arm above avoids.
+            if called_name == Some("sum") {
+                if let Some(start) = call.arguments.args.get(1) {
+                    return infer_expr_type(db, file_id, start);
+                }
+                if let Some(keyword) = call.arguments.find_keyword("start") {
+                    return infer_expr_type(db, file_id, &keyword.value);
+                }
+            }
+
+            // `getattr(obj, "name", default)` with a literal attribute name returns the union of
+            // whatever `get_member` resolves `"name"` to on `obj`'s type (the same lookup
+            // `Expr::Attribute` above uses) and `default`'s own type, since either could be the
+            // result at runtime. This does not narrow `(v := getattr(obj, "x", None)) is not
+            // None`, though: `infer_constraint_type` only narrows a bare name on the left of
+            // `is`/`is not`, so for a walrus on the left `v` keeps the full union, `None`
+            // included, inside the branch. The two-argument
+            // form, `getattr(obj, "name")`, is deliberately left alone here: with no `default` to
+            // catch it, a missing attribute is an `AttributeError`, and silently mapping that to
+            // `Unknown` the way `Expr::Attribute` above does would hide the same missing-attribute
+            // case its own TODO already flags as needing a real diagnostic instead. A non-literal
+            // `name` falls through to `Unknown` below in both forms, since there's nothing to
+            // look up.
+            if called_name == Some("getattr") && call.arguments.args.len() == 3 {
+                if let ast::Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) =
+                    &call.arguments.args[1]
+                {
+                    let obj_ty = infer_expr_type(db, file_id, &call.arguments.args[0])?;
+                    let default_ty = infer_expr_type(db, file_id, &call.arguments.args[2])?;
+                    let attr_ty = obj_ty
+                        .get_member(db, &Name::new(value.to_str()))?
+                        .unwrap_or(Type::Unknown);
+                    let jar: &SemanticJar = db.jar()?;
+                    return Ok(jar.type_store.add_union(file_id, &[attr_ty, default_ty]));
+                }
+                // A non-literal `name` (e.g. `getattr(obj, some_str_var, default)`) can't be
+                // looked up by `get_member` at all -- there's no name to look up. The real
+                // fallback in that case is `obj`'s own `__getattr__`, if it defines one: Python
+                // calls it with whatever name was passed. Resolving *that* needs the same
+                // "what does this callable return" query the `itertools.groupby` case below is
+                // also missing on `FunctionType`, so this case still falls through to `Unknown`.
+            }
+
+            // `re.match`/`re.search` should return `re.Match[str | bytes] | None`, parameterized
+            // by whichever of `str`/`bytes` the pattern/string arguments are. Beyond needing the
+            // same specialized generic `Type::Instance` the `dict.fromkeys` case below needs for
+            // `re.Match[...]` itself, the `str`/`bytes` half of that also needs builtins
+            // resolution (see the `str.encode`/`bytes.decode` case below) to tell which one a
+            // given string argument's type actually is.
+            //
+            // `collections.Counter(iterable)` should produce a `Counter[E]` specialized by the
+            // iterable's own element type `E`, so that its subscript access and `.most_common()`
+            // can be typed as `int` and `list[tuple[E, int]]` respectively. Both halves are gaps
+            // already called out elsewhere in this arm: `E` itself needs the iterable-element
+            // extraction the `min`/`max` arm above avoids, and holding `E` on the resulting
+            // `Counter` instance at all needs the specialized generic `Type::Instance` the
+            // `dict.fromkeys` case below is also waiting on.
+            //
+            // `dataclasses.asdict`/`astuple` should return a `dict`/`tuple` built from the
+            // dataclass's own field types, not just the broad `dict[str, Any]`/`tuple[Any, ...]`
+            // the stubs declare. That needs `Type` to be able to represent a specialized generic
+            // instance at all (a class plus its type arguments) -- `Type::Instance` only carries
+            // a bare `ClassTypeId` today -- so this falls through to `Unknown` below along with
+            // every other unrecognized call.
+            //
+            // `dict.fromkeys(keys)`/`dict.fromkeys(keys, value)` should return a `dict` keyed by
+            // `keys`'s element type, with `None` or `value`'s type as the value type. Like the
+            // `dataclasses.asdict`/`astuple` case above, that needs a specialized generic
+            // `Type::Instance` to even express "a `dict` of these two types" -- today's bare
+            // `Type::Instance(ClassTypeId)` has nowhere to put either type argument.
+            //
+            // `itertools.groupby(iterable, key=...)` should produce an iterator of
+            // `tuple[K, Iterator[E]]`, with `K` the `key` callback's own return type (defaulting
+            // to `E`, the source iterable's element type, when no `key` is given). Both halves of
+            // that are missing pieces: the iterable's element type `E` needs the same
+            // iterable-element extraction the `min`/`max` arm above already avoids, and `K`
+            // additionally needs a way to ask "what does this callable return", which doesn't
+            // exist either -- there's no return-type field or query anywhere on `FunctionType`.
+            //
+            // `os.path.join`/`os.path.splitext` and friends are `str` in, `str` out (`bytes` in,
+            // `bytes` out; `splitext` specifically returning a 2-tuple). Recognizing them by name
+            // via `called_function_name` is the easy part; returning the right type for them
+            // still needs the same builtins resolution gap as `str.encode`/`bytes.decode` below
+            // (there's no resolved `str`/`bytes` class to hand back), and `splitext` additionally
+            // needs a 2-tuple `Type` shape, which doesn't exist independent of the
+            // specialized-generic-instance gap noted throughout this arm.
+            //
+            // `str.encode()`/`bytes.decode()` should flip between `str` and `bytes` rather than
+            // both landing on `Unknown` here. Unlike the calls handled above, recognizing this
+            // one isn't a matter of `called_function_name` alone: it needs the *receiver*'s type
+            // to already be known as "the builtin `str` class" or "the builtin `bytes` class"
+            // specifically, and there's no builtins/typeshed module resolution anywhere in this
+            // crate yet -- `Expr::Name` resolution only looks in the current module's own scope
+            // (see its TODO above), so a bare `str`/`bytes` literal value's class can't be
+            // distinguished from any other unresolved name in the first place.
+            Ok(Type::Unknown)
+        }
+        // TODO resolve `__getitem__` (and pick the right overload, e.g. index vs. slice argument,
+        // once overloads are modeled) instead of giving up on every subscript. A `TypedDict`
+        // instance's subscript (`d["name"]`) is a special case of this worth calling out
+        // separately: once `TypedDict` fields exist at all (see the `Type` enum's TODO), this
+        // should look up a string-literal key's declared value type directly from the fields
+        // rather than going through `__getitem__` at all (a `TypedDict` doesn't have a real
+        // `__getitem__` signature to resolve in the first place -- it's checked structurally by
+        // type checkers, not via the runtime `dict.__getitem__` inherited at the value level),
+        // flag a key that isn't one of the fields, and fall back to the union of every field's
+        // value type for a non-literal key.
+        ast::Expr::Subscript(_) => Ok(Type::Unknown),
+        // TODO `yield` should be typed as the type sent in via `.send()` (usually `None`), and
+        // `yield from x` should validate that `x` is iterable (emitting a diagnostic otherwise)
+        // and evaluate to `x`'s sub-generator return type, with the `yield from` expression
+        // itself yielding `x`'s element type. None of this is possible without resolving the
+        // iterable protocol (`__iter__`/`__next__`) on `x`'s type, so for now both just fall back
+        // to `Unknown` rather than panicking.
+        ast::Expr::Yield(_) | ast::Expr::YieldFrom(_) => Ok(Type::Unknown),
+        // TODO `await x` should validate that `x` is awaitable (has `__await__`, or is a
+        // coroutine/`Task`/`Future`) and evaluate to its result type, the same way resolving the
+        // awaitable protocol would need to for `yield from` above. This also blocks
+        // `asyncio.create_task(coro())`/`asyncio.ensure_future(coro())` from specializing their
+        // result type from `coro()`'s own result: there's no `Task[R]`/`Future[R]` representation
+        // to specialize in the first place (no generic instance, see the `Type` enum's TODO), and
+        // even a non-generic "recognize `create_task`, return whatever its coroutine argument
+        // would resolve to on `await`" shortcut needs this same awaitable-protocol resolution to
+        // get `coro()`'s result type out of its `Type::Function` in the first place (a coroutine
+        // function's return annotation isn't inspected anywhere -- see the parameter-list TODO on
+        // `FunctionType` for the broader gap). For now `await` just falls back to `Unknown` rather
+        // than panicking.
+        ast::Expr::Await(_) => Ok(Type::Unknown),
+        // TODO infer `builtins.list`/`set`/`dict`/`typing.Generator` specialized with the
+        // comprehension's element type(s), the way the literal TODO above describes. A
+        // comprehension (`ListComp`/`SetComp`/`DictComp`/`Generator`) doesn't get a scope of its
+        // own (see the TODO on `Expr::Named` in `semantic.rs`): everything inside it is indexed
+        // into the enclosing scope, which is where the `elt` (or `key`/`value`) expression's type
+        // would be inferred -- an `if` filter clause or a nested comprehension
+        // inside it doesn't change that element type, only which elements are produced. The
+        // `Generator[...]`'s yield/send type parameters can stay `Unknown` for now, but the
+        // yielded element type shouldn't.
+        ast::Expr::ListComp(_)
+        | ast::Expr::SetComp(_)
+        | ast::Expr::DictComp(_)
+        | ast::Expr::Generator(_) => Ok(Type::Unknown),
+        // TODO every comparison operator (`<`, `==`, `in`, `not in`, ...) always evaluates to
+        // `Unknown` here rather than the `bool` its dunder actually returns, for two reasons: (1)
+        // there's no dunder-dispatch for any of them yet (`__lt__`/`__eq__`/`__contains__`, nor
+        // the fallback to `__iter__` or a `str`/`bytes` substring check that `in`/`not in`
+        // specifically should fall back to when `__contains__` is absent), and (2) even a
+        // best-effort "is this one of the common correct shapes" check has nowhere to report a
+        // mismatch to -- there's no `UNSUPPORTED_OPERATOR`-style diagnostic code, only the bare
+        // `push_diagnostic(String)` every lint in `lint.rs` already uses, and this is a type query
+        // rather than a lint, so it has no `SemanticLintContext` to push through in the first
+        // place. A chained comparison (`a < b < c`, represented as a single `ExprCompare` with
+        // `ops: [Lt, Lt]` and `comparators: [b, c]`) additionally needs each adjacent pair (`a <
+        // b`, `b < c`) checked and its dunder's return type unioned in independently, rather than
+        // a single `bool` assumed for the whole chain -- many `__lt__`/`__gt__`/etc.
+        // implementations return `NotImplemented` (triggering the reflected method on the other
+        // operand) or a custom type rather than `bool` (NumPy's elementwise comparisons being the
+        // classic example, returning an array rather than a scalar), and Python's short-circuit
+        // evaluation order for the chain (each operand evaluated left-to-right exactly once, the
+        // chain short-circuiting to `False` at the first falsy pairwise result) would need
+        // preserving too. None of this is possible without the dunder-dispatch machinery the
+        // paragraph above already says doesn't exist.
+        ast::Expr::Compare(_) => Ok(Type::Unknown),
+        // TODO an f-string should evaluate to `str` (its elements can only ever produce a
+        // `str`), but more importantly, neither an f-string's `{expr}` placeholders nor a
+        // `"{} {}".format(a, b)`/`"%s" % x` call's placeholders are checked against the number of
+        // values supplied anywhere: there's no parser here for the `%`-style or `str.format`-style
+        // mini-languages embedded in a `StringLiteral`'s value (counting `%s`/`%d`/etc.
+        // conversions, or counting `{}`/`{0}`/`{name}` fields while correctly skipping escaped
+        // `{{`/`}}`), so a placeholder/argument-count mismatch -- which raises `TypeError` or
+        // `IndexError` at runtime -- goes completely undetected. `%` formatting doesn't even reach
+        // this arm today: it's parsed as an ordinary `BinOp` with `Mod`, so catching it needs
+        // `resolve_bin_op`'s `Mod` case to special-case a `str`/`bytes` left operand the same way
+        // this arm would need to for `.format()`, not a change here. For now f-strings just fall
+        // back to `Unknown` rather than panicking.
+        ast::Expr::FString(_) => Ok(Type::Unknown),
+        // TODO these should resolve to the builtin `str`/`bytes`/`bool` instance types and
+        // `EllipsisType` respectively, the same way `NumberLiteral` resolves to `IntLiteral`
+        // above -- blocked on the same missing builtins/typeshed resolution noted throughout
+        // this function (see the `Expr::Call` arm's `str.encode`/`bytes.decode` case). For now
+        // they fall back to `Unknown` rather than panicking on the catch-all `todo!` below.
+        ast::Expr::StringLiteral(_)
+        | ast::Expr::BytesLiteral(_)
+        | ast::Expr::BooleanLiteral(_)
+        | ast::Expr::EllipsisLiteral(_) => Ok(Type::Unknown),
         _ => todo!("expression type resolution for {:?}", expr),
     }
 }
 
+/// Returns the bare or qualified name a call's callee resolves to syntactically -- `"min"` for
+/// both `min(...)` and `builtins.min(...)` -- for recognizing a handful of well-known functions
+/// by name in [`infer_expr_type`]'s `Expr::Call` arm before there's a real callable `Type` to
+/// dispatch on.
+fn called_function_name(call: &ast::ExprCall) -> Option<&str> {
+    match call.func.as_ref() {
+        ast::Expr::Name(ast::ExprName { id, .. }) => Some(id.as_str()),
+        ast::Expr::Attribute(ast::ExprAttribute { attr, .. }) => Some(attr.id.as_str()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -572,6 +972,108 @@ mod tests {
         assert_public_type(&case, "a", "x", "Literal[1] | Unbound")
     }
 
+    #[test]
+    fn maybe_unbound_in_try_body() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                try:
+                    y = 1
+                except:
+                    pass
+                x = y
+            ",
+        )?;
+
+        assert_public_type(&case, "a", "x", "Literal[1] | Unbound")
+    }
+
+    #[test]
+    fn except_handler_binds_exception_instance_type() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class MyError(Exception): pass
+                try:
+                    pass
+                except MyError as e:
+                    x = e
+            ",
+        )?;
+
+        assert_public_type(&case, "a", "x", "MyError")
+    }
+
+    #[test]
+    fn except_handler_tuple_binds_union_type() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class A(Exception): pass
+                class B(Exception): pass
+                try:
+                    pass
+                except (A, B) as e:
+                    x = e
+            ",
+        )?;
+
+        assert_public_type(&case, "a", "x", "A | B")
+    }
+
+    #[test]
+    fn except_handler_narrows_via_isinstance() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class A(Exception): pass
+                class B(Exception): pass
+                try:
+                    pass
+                except (A, B) as e:
+                    if isinstance(e, A):
+                        x = e
+            ",
+        )?;
+
+        // TODO normalization of unions and intersections: this type is technically correct but
+        // begging for normalization (see the TODO on `narrow_none` below)
+        assert_public_type(&case, "a", "x", "A | B & A")
+    }
+
+    #[test]
+    fn except_handler_name_unbound_after_handler() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                try:
+                    pass
+                except Exception as e:
+                    pass
+                x = e
+            ",
+        )?;
+
+        // CPython implicitly does `del e` on handler exit, so a read of `e` after the `try` sees
+        // it as unbound rather than still typed as the exception.
+        assert_public_type(&case, "a", "x", "Unbound")
+    }
+
     #[test]
     fn if_elif_else() -> anyhow::Result<()> {
         let case = create_test()?;
@@ -761,4 +1263,58 @@ mod tests {
         // begging for normalization
         assert_public_type(&case, "a", "z", "Literal[0] | Literal[1] | None & ~None")
     }
+
+    #[test]
+    fn min_max_union_of_candidates() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                x = min(1, 2)
+            ",
+        )?;
+
+        assert_public_type(&case, "a", "x", "Literal[1, 2]")
+    }
+
+    #[test]
+    fn sum_with_explicit_start_takes_starts_type() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                x = sum([1, 2, 3], 100)
+            ",
+        )?;
+
+        assert_public_type(&case, "a", "x", "Literal[100]")
+    }
+
+    #[test]
+    fn getattr_with_default_narrows_through_is_not_none() -> anyhow::Result<()> {
+        let case = create_test()?;
+
+        write_to_path(
+            &case,
+            "a.py",
+            "
+                class C:
+                    attr = 1
+
+                obj = C()
+                y = 0
+                if (v := getattr(obj, 'attr', None)) is not None:
+                    y = v
+                z = y
+            ",
+        )?;
+
+        // TODO normalization of unions and intersections: this type is technically correct but
+        // begging for normalization
+        assert_public_type(&case, "a", "z", "Literal[0] | Literal[1] | None & ~None")
+    }
 }